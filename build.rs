@@ -0,0 +1,23 @@
+use std::process::Command;
+
+fn main() {
+    for path in [".git/HEAD", ".git/index", ".git/refs", "src"] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    if let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) {
+        let dirty = git(&["status", "--porcelain", "--untracked-files=no"]).is_some_and(|x| !x.is_empty());
+        println!("cargo:rustc-env=AOC_BUILD_COMMIT={}{}", commit, if dirty { "-dirty" } else { "" });
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+    else {
+        None
+    }
+}
@@ -127,7 +127,7 @@ impl <T: Clone> Clone for Matrix<T> {
 impl <T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in self.rows.iter() {
-            write!(f, "{}\n", row)?
+            writeln!(f, "{}", row)?
         }
 
         Result::Ok(())
@@ -137,7 +137,7 @@ impl <T: Display> Display for Matrix<T> {
 impl <T: Debug> Debug for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in self.rows.iter() {
-            write!(f, "{:?}\n", row)?
+            writeln!(f, "{:?}", row)?
         }
 
         Result::Ok(())
@@ -187,9 +187,8 @@ impl Matrix<f64> {
         let cols = self.column_count();
         let mut m = self.clone();
         let mut result_content: Vec<f64> = vec![0.0; rows];
-        let mut row = 0;
 
-        for col in 0..(cols - 1) {
+        for (row, col) in (0..(cols - 1)).enumerate() {
             let mut column_data: Vec<(usize, f64)> = self.get_column(col).content.iter().enumerate().skip(row).map(|(i, &v)| (i, v)).collect();
             column_data.sort_by(|a, b| a.1.abs().partial_cmp(&b.1.abs()).unwrap_or(std::cmp::Ordering::Equal));
             let max_index = column_data.last().unwrap().0;
@@ -206,19 +205,17 @@ impl Matrix<f64> {
                 let fraction = m.rows[i].content[col] / m.rows[row].content[col];
 
                 for j in (col + 1)..cols {
-                    m.rows[i].content[j] = m.rows[i].content[j] -  m.rows[row].content[j] * fraction;
+                    m.rows[i].content[j] -= m.rows[row].content[j] * fraction;
                 }
 
                 m.rows[i].content[col] = 0.0;
             }
-
-            row += 1;
         }
 
         for i in (0..=(rows - 1)).rev() {
             let mut sum = 0.0;
             for j in (i..=(rows - 1)).rev() {
-                sum = sum + result_content[j] * m.rows[i].content[j];
+                sum += result_content[j] * m.rows[i].content[j];
             }
             result_content[i] = (m.rows[i].content[cols - 1] - sum) / m.rows[i].content[i];
         }
@@ -0,0 +1,199 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::solver::Answer;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "inputs/answers.txt";
+
+pub fn answers_path(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("inputs/{}/answers.txt", profile),
+        None => DEFAULT_ANSWERS_PATH.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooLow,
+    TooHigh,
+    Wrong
+}
+
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    accepted: Option<String>,
+    rejected: Vec<(Hint, String)>
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerBook {
+    entries: HashMap<(u32, u32), KnownAnswers>
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown(Option<String>)
+}
+
+#[derive(Debug)]
+pub enum AnswerBookError {
+    Io(String, std::io::Error),
+    Parse(usize, String)
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooLow => write!(f, "too low"),
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::Wrong => write!(f, "wrong")
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(reason) => write!(f, "FAIL ({})", reason),
+            Verdict::Unknown(None) => write!(f, "UNKNOWN"),
+            Verdict::Unknown(Some(note)) => write!(f, "UNKNOWN ({})", note)
+        }
+    }
+}
+
+impl Display for AnswerBookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerBookError::Io(path, error) => write!(f, "Could not read {}: {}", path, error),
+            AnswerBookError::Parse(line, text) => write!(f, "Invalid answer entry on line {}: {:?} (expected `<day> <part> <accepted|too-low|too-high|wrong> <answer>`)", line, text)
+        }
+    }
+}
+
+impl AnswerBook {
+    pub fn load(path: &str) -> Result<AnswerBook, AnswerBookError> {
+        let content = std::fs::read_to_string(path).map_err(|x| AnswerBookError::Io(path.to_string(), x))?;
+        AnswerBook::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<AnswerBook, AnswerBookError> {
+        let mut book = AnswerBook::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || AnswerBookError::Parse(index + 1, line.to_string());
+            let mut segments = trimmed.splitn(4, char::is_whitespace);
+            let day: u32 = segments.next().and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+            let part: u32 = segments.next().and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+            let status = segments.next().ok_or_else(invalid)?;
+            let answer = segments.next().map(|x| x.trim().to_string()).filter(|x| !x.is_empty()).ok_or_else(invalid)?;
+            let entry = book.entries.entry((day, part)).or_default();
+
+            match status {
+                "accepted" => { entry.accepted = Some(answer); }
+                "too-low" => { entry.rejected.push((Hint::TooLow, answer)); }
+                "too-high" => { entry.rejected.push((Hint::TooHigh, answer)); }
+                "wrong" => { entry.rejected.push((Hint::Wrong, answer)); }
+                _ => { return Err(invalid()); }
+            }
+        }
+
+        Ok(book)
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &Answer) -> Verdict {
+        let Some(entry) = self.entries.get(&(day, part)) else {
+            return Verdict::Unknown(None);
+        };
+
+        let answer_string = answer.to_string();
+
+        for (hint, rejected) in entry.rejected.iter() {
+            if *rejected == answer_string {
+                return Verdict::Fail(format!("{} was already rejected as {}", rejected, hint));
+            }
+
+            if let (Answer::Integer(value), Ok(bound)) = (answer, rejected.parse::<i64>()) {
+                let violates = match hint {
+                    Hint::TooLow => *value <= bound,
+                    Hint::TooHigh => *value >= bound,
+                    Hint::Wrong => false
+                };
+
+                if violates {
+                    return Verdict::Fail(format!("{} is {}", rejected, hint));
+                }
+            }
+        }
+
+        match &entry.accepted {
+            Some(accepted) if *accepted == answer_string => Verdict::Pass,
+            Some(accepted) => Verdict::Fail(format!("expected {}", accepted)),
+            None if entry.rejected.is_empty() => Verdict::Unknown(None),
+            None => {
+                let notes: Vec<String> = entry.rejected.iter().map(|(hint, rejected)| format!("{} is {}", rejected, hint)).collect();
+                Verdict::Unknown(Some(notes.join(", ")))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerBook, AnswerBookError, Verdict};
+    use crate::solver::Answer;
+
+    fn check(content: &str, answer: impl Into<Answer>) -> Verdict {
+        AnswerBook::parse(content).unwrap().check(1, 1, &answer.into())
+    }
+
+    #[test]
+    fn check_applies_bounds() {
+        let book = "1 1 too-low 100\n1 1 too-high 200\n";
+
+        assert_eq!(check(book, 100), Verdict::Fail("100 was already rejected as too low".to_string()));
+        assert_eq!(check(book, 50), Verdict::Fail("100 is too low".to_string()));
+        assert_eq!(check(book, 250), Verdict::Fail("200 is too high".to_string()));
+        assert_eq!(check(book, 150), Verdict::Unknown(Some("100 is too low, 200 is too high".to_string())));
+    }
+
+    #[test]
+    fn check_matches_rejected_guesses() {
+        let book = "1 1 wrong 150\n1 1 wrong abc\n";
+
+        assert_eq!(check(book, 150), Verdict::Fail("150 was already rejected as wrong".to_string()));
+        assert_eq!(check(book, "abc".to_string()), Verdict::Fail("abc was already rejected as wrong".to_string()));
+        assert_eq!(check(book, 151), Verdict::Unknown(Some("150 is wrong, abc is wrong".to_string())));
+    }
+
+    #[test]
+    fn check_compares_accepted_answer_regardless_of_line_order() {
+        for book in ["1 1 too-low 100\n1 1 accepted 120\n", "1 1 accepted 120\n1 1 too-low 100\n"] {
+            assert_eq!(check(book, 120), Verdict::Pass);
+            assert_eq!(check(book, 130), Verdict::Fail("expected 120".to_string()));
+            assert_eq!(check(book, 90), Verdict::Fail("100 is too low".to_string()));
+        }
+    }
+
+    #[test]
+    fn check_reports_unknown_without_entry() {
+        assert_eq!(check("# comment\n\n2 1 accepted 5\n", 5), Verdict::Unknown(None));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+        for (content, line) in [("1 1 accepted\n", 1), ("x 1 accepted 5\n", 1), ("1\n", 1), ("# ok\n1 1 maybe 5\n", 2), ("1 y wrong 5\n", 1)] {
+            match AnswerBook::parse(content) {
+                Err(AnswerBookError::Parse(number, _)) => assert_eq!(number, line, "{:?}", content),
+                result => panic!("{:?} parsed as {:?}", content, result)
+            }
+        }
+    }
+}
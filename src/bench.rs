@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use crate::{input::InputSource, params::Params, runner::{self, PhaseTimings}, solver::{Answer, Solver, SolverError}};
+
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u32,
+    pub iterations: usize,
+    pub answer: Answer,
+    pub read: Statistics,
+    pub parse: Statistics,
+    pub solve: Statistics,
+    pub total: Statistics
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Statistics {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(|x| x.as_secs_f64()).sum::<f64>() / count;
+        let variance = sorted.iter().map(|x| (x.as_secs_f64() - mean).powi(2)).sum::<f64>() / count;
+        let middle = sorted.len() / 2;
+
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        }
+        else {
+            sorted[middle]
+        };
+
+        Statistics {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt())
+        }
+    }
+}
+
+pub fn bench(solver: &Solver, part: u32, source: InputSource, params: &Params, warmup: usize, iterations: usize) -> Result<BenchResult, SolverError> {
+    solver.check_part(part)?;
+
+    let source = source.buffered()?;
+
+    for _ in 0..warmup {
+        runner::run(solver, part, &source, params, None)?;
+    }
+
+    let mut samples: Vec<PhaseTimings> = vec![];
+    let mut answer = None;
+
+    for _ in 0..iterations.max(1) {
+        let result = runner::run(solver, part, &source, params, None)?;
+        samples.push(result.timings);
+        answer = Some(result.answer);
+    }
+
+    let phase = |f: fn(&PhaseTimings) -> Duration| Statistics::from_samples(&samples.iter().map(f).collect::<Vec<Duration>>());
+
+    Ok(BenchResult {
+        day: solver.day,
+        part,
+        iterations: samples.len(),
+        answer: answer.unwrap(),
+        read: phase(|x| x.read),
+        parse: phase(|x| x.parse),
+        solve: phase(|x| x.solve),
+        total: phase(|x| x.total())
+    })
+}
+
+pub fn print(result: &BenchResult) {
+    println!("Day {} Part {}: {} ({} iterations)", result.day, result.part, result.answer, result.iterations);
+    println!("{:<6}  {:>12}  {:>12}  {:>12}  {:>12}", "Phase", "Min (s)", "Median (s)", "Mean (s)", "Stddev (s)");
+
+    for (name, statistics) in [("read", &result.read), ("parse", &result.parse), ("solve", &result.solve), ("total", &result.total)] {
+        println!(
+            "{:<6}  {:>12.6}  {:>12.6}  {:>12.6}  {:>12.6}",
+            name,
+            statistics.min.as_secs_f64(),
+            statistics.median.as_secs_f64(),
+            statistics.mean.as_secs_f64(),
+            statistics.stddev.as_secs_f64()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Statistics;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    #[test]
+    fn from_samples_takes_middle_of_odd_count() {
+        let statistics = Statistics::from_samples(&millis(&[9, 1, 5]));
+
+        assert_eq!(statistics.min, Duration::from_millis(1));
+        assert_eq!(statistics.median, Duration::from_millis(5));
+        assert_eq!(statistics.mean, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn from_samples_averages_middle_pair_of_even_count() {
+        let statistics = Statistics::from_samples(&millis(&[8, 2, 4, 6]));
+
+        assert_eq!(statistics.min, Duration::from_millis(2));
+        assert_eq!(statistics.median, Duration::from_millis(5));
+        assert!((statistics.stddev.as_secs_f64() - 5.0f64.sqrt() / 1000.0).abs() < 1e-9);
+    }
+}
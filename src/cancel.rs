@@ -0,0 +1,44 @@
+use std::{cell::RefCell, sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex}, thread::JoinHandle};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+static ABANDONED: Mutex<Vec<JoinHandle<()>>> = Mutex::new(vec![]);
+
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn install(&self) {
+        CURRENT.with(|x| *x.borrow_mut() = Some(self.clone()));
+    }
+}
+
+pub fn is_cancelled() -> bool {
+    CURRENT.with(|x| x.borrow().as_ref().is_some_and(|token| token.is_cancelled()))
+}
+
+pub fn abandon(handle: JoinHandle<()>) {
+    ABANDONED.lock().unwrap().push(handle);
+}
+
+pub fn still_running() -> usize {
+    let mut abandoned = ABANDONED.lock().unwrap();
+    abandoned.retain(|x| !x.is_finished());
+    abandoned.len()
+}
@@ -0,0 +1,132 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+pub struct Arguments {
+    positional: Vec<String>,
+    options: HashMap<String, Vec<String>>
+}
+
+#[derive(Debug)]
+pub enum ArgumentError {
+    MissingValue(String),
+    UnknownOption(String),
+    InvalidValue(String, String)
+}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgumentError::MissingValue(name) => write!(f, "Option --{} requires a value", name),
+            ArgumentError::UnknownOption(name) => write!(f, "Unknown option --{}", name),
+            ArgumentError::InvalidValue(name, value) => write!(f, "Invalid value {:?} for {}", value, name)
+        }
+    }
+}
+
+impl Arguments {
+    pub fn parse(args: impl Iterator<Item = String>, flags: &[&str], accepted: &[&str]) -> Result<Arguments, ArgumentError> {
+        let mut positional = vec![];
+        let mut options: HashMap<String, Vec<String>> = HashMap::new();
+        let mut args = args.peekable();
+        let known = |name: &str| flags.contains(&name) || accepted.contains(&name);
+
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                positional.push(arg);
+                continue;
+            };
+
+            if let Some((name, value)) = name.split_once('=') {
+                if !known(name) {
+                    return Err(ArgumentError::UnknownOption(name.to_string()));
+                }
+
+                options.entry(name.to_string()).or_default().push(value.to_string());
+            }
+            else if flags.contains(&name) {
+                options.entry(name.to_string()).or_default().push(String::new());
+            }
+            else if !accepted.contains(&name) {
+                return Err(ArgumentError::UnknownOption(name.to_string()));
+            }
+            else {
+                let value = args.next().ok_or_else(|| ArgumentError::MissingValue(name.to_string()))?;
+                options.entry(name.to_string()).or_default().push(value);
+            }
+        }
+
+        Ok(Arguments {
+            positional,
+            options
+        })
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(|x| x.as_str())
+    }
+
+    pub fn positional_or<T: FromStr>(&self, index: usize, name: &str, default: T) -> Result<T, ArgumentError> {
+        match self.positional(index) {
+            Some(value) => value.parse().map_err(|_| ArgumentError::InvalidValue(name.to_string(), value.to_string())),
+            None => Ok(default)
+        }
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|x| x.last()).map(|x| x.as_str())
+    }
+
+    pub fn options(&self, name: &str) -> &[String] {
+        self.options.get(name).map(|x| x.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn option_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, ArgumentError> {
+        match self.option(name) {
+            Some(value) => value.parse().map_err(|_| ArgumentError::InvalidValue(format!("--{}", name), value.to_string())),
+            None => Ok(default)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentError, Arguments};
+
+    fn parse(args: &[&str]) -> Result<Arguments, ArgumentError> {
+        Arguments::parse(args.iter().map(|x| x.to_string()), &["watch"], &["param", "threads"])
+    }
+
+    #[test]
+    fn parse_separates_flags_from_positional_arguments() {
+        let arguments = parse(&["6", "--watch", "1"]).unwrap();
+
+        assert_eq!(arguments.option("watch"), Some(""));
+        assert_eq!(arguments.positional(0), Some("6"));
+        assert_eq!(arguments.positional(1), Some("1"));
+        assert_eq!(arguments.positional(2), None);
+    }
+
+    #[test]
+    fn parse_accepts_inline_and_separate_values() {
+        let arguments = parse(&["--threads=4", "--param", "width=11"]).unwrap();
+
+        assert_eq!(arguments.option_or("threads", 1).unwrap(), 4);
+        assert_eq!(arguments.option("param"), Some("width=11"));
+    }
+
+    #[test]
+    fn parse_collects_repeated_options() {
+        let arguments = parse(&["--param", "width=11", "--param=height=7", "--threads", "2", "--threads", "3"]).unwrap();
+
+        assert_eq!(arguments.options("param"), ["width=11", "height=7"]);
+        assert_eq!(arguments.option("threads"), Some("3"));
+        assert!(arguments.options("watch").is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_and_incomplete_options() {
+        assert!(matches!(parse(&["--sequentail", "6", "1"]), Err(ArgumentError::UnknownOption(x)) if x == "sequentail"));
+        assert!(matches!(parse(&["--colour=red"]), Err(ArgumentError::UnknownOption(x)) if x == "colour"));
+        assert!(matches!(parse(&["--threads"]), Err(ArgumentError::MissingValue(x)) if x == "threads"));
+        assert!(matches!(parse(&["--threads=x"]).unwrap().option_or("threads", 1), Err(ArgumentError::InvalidValue(_, _))));
+    }
+}
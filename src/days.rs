@@ -0,0 +1,38 @@
+use crate::solver::Solver;
+
+#[cfg(test)]
+macro_rules! examples {
+    ($solution:ty; $($name:ident: part $part:literal, $input:expr $(, [$($param:literal),*])? => $expected:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                crate::solver::check_example::<$solution>($input, $part, &[$($($param),*)?], $expected);
+            }
+        )*
+    };
+}
+
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub static SOLVERS: &[Solver] = &[$($day::SOLVER),*];
+    };
+}
+
+days!(
+    day1,
+    day2,
+    day3,
+    day4,
+    day5,
+    day6,
+    day7,
+    day8,
+    day9,
+    day10,
+    day11,
+    day12,
+    day13,
+    day14,
+);
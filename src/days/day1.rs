@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::params::Params;
+use crate::parse::{parse_number, ParseError};
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct Day1;
+
+pub const SOLVER: Solver = Solver::of::<Day1>();
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((left_list, right_list): &Self::Input, _params: &Params) -> Answer {
+        let (left_list, right_list) = sort_lists(left_list, right_list);
+
+        let total = Iterator::zip(left_list.into_iter(), right_list)
+            .map(|(x, y)| (x - y).abs())
+            .sum::<i64>();
+
+        total.into()
+    }
+
+    fn part2((left_list, right_list): &Self::Input, _params: &Params) -> Answer {
+        let frequencies = count_frequencies(right_list);
+
+        let total = left_list.iter()
+            .map(|x| x * frequencies.get(x).unwrap_or(&0))
+            .sum::<i64>();
+
+        total.into()
+    }
+
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let lines: Vec<String> = (0..size).map(|_| format!("{}   {}", random.range(10000, 99999), random.range(10000, 99999))).collect();
+        Some(lines.join("\n"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let mut first_list: Vec<i64> = Vec::new();
+    let mut second_list: Vec<i64> = Vec::new();
+
+    for line in input.lines() {
+        let mut split_line = line.split_whitespace();
+        let missing = || ParseError::at(input, &line[line.len()..], "expected two location IDs");
+        first_list.push(parse_number(input, split_line.next().ok_or_else(missing)?)?);
+        second_list.push(parse_number(input, split_line.next().ok_or_else(missing)?)?);
+    }
+
+    Ok((first_list, second_list))
+}
+
+fn sort_lists(left_list: &[i64], right_list: &[i64]) -> (Vec<i64>, Vec<i64>) {
+    let mut left_list = left_list.to_vec();
+    let mut right_list = right_list.to_vec();
+
+    left_list.sort();
+    right_list.sort();
+
+    (left_list, right_list)
+}
+
+fn count_frequencies(input: &[i64]) -> HashMap<i64, i64> {
+    let mut frequencies: HashMap<i64, i64> = HashMap::new();
+
+    for &v in input {
+        *frequencies.entry(v).or_insert(0) += 1;
+    }
+
+    frequencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day1;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day1>();
+    }
+
+    examples! {
+        Day1;
+        part1_example: part 1, EXAMPLE => 11;
+        part2_example: part 2, EXAMPLE => 31;
+    }
+}
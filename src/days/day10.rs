@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use crate::geometry::{Direction, Grid, Point, RaggedRows};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct Day10;
+
+pub const SOLVER: Solver = Solver::of::<Day10>();
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Grid<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(number_grid: &Self::Input, _params: &Params) -> Answer {
+        solve(number_grid, false).into()
+    }
+
+    fn part2(number_grid: &Self::Input, _params: &Params) -> Answer {
+        solve(number_grid, true).into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<i64>, ParseError> {
+    Grid::parse(input, RaggedRows::Reject, |_, c| match c {
+        '.' => Ok(-1),
+        c => c.to_digit(10).map(|x| x as i64).ok_or("expected a height digit or `.`")
+    })
+}
+
+fn solve(number_grid: &Grid<i64>, distinct: bool) -> i64 {
+    number_grid
+        .find_all(|&x| x == 0)
+        .map(|x| count_paths(number_grid, x.0))
+        .fold(0, |a, b| a + if distinct { b.1 } else { b.0 })
+}
+
+fn count_paths(grid: &Grid<i64>, start_position: Point) -> (i64, i64) {
+    let mut check_stack = vec![start_position];
+    let mut ending_locations: HashSet<Point> = HashSet::new();
+    let mut distinct_trails = 0;
+
+    while let Some(current_position) = check_stack.pop() {
+        let &current_value = grid.get(current_position).unwrap();
+
+        if current_value == 9 {
+            ending_locations.insert(current_position);
+            distinct_trails += 1;
+        }
+        else {
+            let neighbors = [
+                grid.get_with_index(current_position + Direction::North),
+                grid.get_with_index(current_position + Direction::East),
+                grid.get_with_index(current_position + Direction::South),
+                grid.get_with_index(current_position + Direction::West)
+            ];
+
+            let valid_neighbors = neighbors
+                .iter()
+                .flatten()
+                .filter(|(_, &x)| x == current_value + 1);
+
+            for neighbor in valid_neighbors {
+                check_stack.push(neighbor.0);
+            }
+        }
+    }
+
+    (ending_locations.len() as i64, distinct_trails)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    examples! {
+        Day10;
+        part1_example: part 1, EXAMPLE => 36;
+        part2_example: part 2, EXAMPLE => 81;
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::params::{Parameter, Params};
+use crate::parse::{parse_number, ParseError};
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct StoneMemoizer {
+    stone_info: HashMap<(u64, u64), u64>
+}
+
+impl StoneMemoizer {
+    fn new() -> StoneMemoizer {
+        StoneMemoizer {
+            stone_info: HashMap::new()
+        }
+    }
+
+    fn check(&self, input: u64, remaining: u64) -> Option<&u64> {
+        self.stone_info.get(&(input, remaining))
+    }
+
+    fn insert(&mut self, input: u64, remaining: u64, value: u64) {
+        self.stone_info.insert((input, remaining), value);
+    }
+}
+
+pub struct Day11;
+
+pub const SOLVER: Solver = Solver::of::<Day11>();
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::bounded("blinks1", 25, 0, 90),
+        Parameter::bounded("blinks2", 75, 0, 90)
+    ];
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input, params: &Params) -> Answer {
+        let mut memoizer = StoneMemoizer::new();
+        let mut sum = 0;
+        for &stone in stones {
+            sum += process_stone(&mut memoizer, stone, params.get("blinks1") as u64);
+        }
+        sum.into()
+    }
+
+    fn part2(stones: &Self::Input, params: &Params) -> Answer {
+        let mut memoizer = StoneMemoizer::new();
+        let mut sum = 0;
+        for &stone in stones {
+            sum += process_stone(&mut memoizer, stone, params.get("blinks2") as u64);
+        }
+        sum.into()
+    }
+}
+
+fn process_stone(memoizer: &mut StoneMemoizer, stone: u64, remaining: u64) -> u64 {
+    let entry = memoizer.check(stone, remaining);
+
+    match entry {
+        Some(&x) => x,
+        None => {
+            if remaining == 0 {
+                1
+            }
+            else if stone == 0 {
+                process_stone(memoizer, 1, remaining - 1)
+            }
+            else {
+                let stone_digits: u32 = stone.ilog10() + 1;
+                let digit_factor = 10u64.pow(stone_digits / 2);
+                let second_half_digits = stone % digit_factor;
+                let first_half_digits = stone / digit_factor;
+
+                if stone_digits.is_multiple_of(2) {
+                    let result1 = process_stone(memoizer, first_half_digits, remaining - 1);
+                    let result2 = process_stone(memoizer, second_half_digits, remaining - 1);
+                    let result = result1 + result2;
+                    memoizer.insert(stone, remaining, result);
+                    result
+                }
+                else {
+                    process_stone(memoizer, stone * 2024, remaining - 1)
+                }
+            }
+        }
+    }
+
+
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<u64>, ParseError> {
+    input_string.split_whitespace().map(|x| parse_number(input_string, x)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+
+    const EXAMPLE: &str = "\
+125 17";
+
+    examples! {
+        Day11;
+        part1_example: part 1, EXAMPLE => 55312;
+        part2_example: part 2, EXAMPLE => 65601038650482_i64;
+    }
+}
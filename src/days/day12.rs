@@ -1,39 +1,43 @@
 use std::collections::HashSet;
 
 use crate::geometry::{Direction, DirectionMap, Grid, Point};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solver::{Answer, Solution, Solver};
 
-pub fn run(input_path: &str, part: i32) {
-    if part <= 1 {
-        part1(input_path);
-    }
-    else {
-        part2(input_path);
+pub struct Day12;
+
+pub const SOLVER: Solver = Solver::of::<Day12>();
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::try_from(input)
     }
-}
 
-fn part1(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
-    let grid = Grid::from(input_string.as_str());
-    let sum = solve(grid, false);
-    println!("{}", sum);
-}
+    fn part1(grid: &Self::Input, _params: &Params) -> Answer {
+        let sum = solve(grid, false);
+        sum.into()
+    }
 
-fn part2(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
-    let grid = Grid::from(input_string.as_str());
-    let sum = solve(grid, true);
-    println!("{}", sum);
+    fn part2(grid: &Self::Input, _params: &Params) -> Answer {
+        let sum = solve(grid, true);
+        sum.into()
+    }
 }
 
-fn solve(grid: Grid<char>, calculate_sides: bool) -> usize {
-    let mut visited: HashSet<Point> = HashSet::new();
+fn solve(grid: &Grid<char>, calculate_sides: bool) -> usize {
+    let mut visited = Grid::new(grid.size, false);
     let mut regions: Vec<Vec<(Point, DirectionMap<bool>)>> = vec![];
     for (p, v) in grid.iter() {
-        if !visited.contains(&p) {
+        if !visited[p] {
             let region = grid.flood_fill(p, |x| x == v);
 
             for (vp, _) in region.iter() {
-                visited.insert(*vp);
+                visited[*vp] = true;
             }
 
             if !region.is_empty() {
@@ -100,4 +104,35 @@ fn calculate_with_sides(regions: &Vec<Vec<(Point, DirectionMap<bool>)>>) -> usiz
         sum += area * sides;
     }
     sum
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+
+    const SMALL_EXAMPLE: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    examples! {
+        Day12;
+        part1_small_example: part 1, SMALL_EXAMPLE => 140;
+        part2_small_example: part 2, SMALL_EXAMPLE => 80;
+        part1_example: part 1, EXAMPLE => 1930;
+        part2_example: part 2, EXAMPLE => 1206;
+    }
+}
@@ -0,0 +1,167 @@
+use crate::algebra::Matrix;
+use crate::params::{Parameter, Params};
+use crate::parse::{parse_number, ParseError};
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct Day13;
+
+pub const SOLVER: Solver = Solver::of::<Day13>();
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::bounded("offset", 10000000000000, 0, 100000000000000),
+        Parameter::bounded("cost_a", 3, 0, 100),
+        Parameter::bounded("cost_b", 1, 0, 100)
+    ];
+
+    type Input = Vec<Matrix<f64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(problems: &Self::Input, params: &Params) -> Answer {
+        solve(problems, (params.get("cost_a"), params.get("cost_b"))).into()
+    }
+
+    fn part2(problems: &Self::Input, params: &Params) -> Answer {
+        let problems = offset_prizes(problems, params.get("offset"));
+        solve(&problems, (params.get("cost_a"), params.get("cost_b"))).into()
+    }
+
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let mut machines = vec![];
+
+        for _ in 0..size {
+            let button_a = (random.range(10, 99), random.range(10, 99));
+            let button_b = (random.range(10, 99), random.range(10, 99));
+
+            let prize = if random.chance(0.5) {
+                let (a, b) = (random.range(0, 100), random.range(0, 100));
+                (a * button_a.0 + b * button_b.0, a * button_a.1 + b * button_b.1)
+            }
+            else {
+                (random.range(1000, 20000), random.range(1000, 20000))
+            };
+
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                button_a.0, button_a.1, button_b.0, button_b.1, prize.0, prize.1
+            ));
+        }
+
+        Some(machines.join("\n\n"))
+    }
+}
+
+fn solve(problems: &[Matrix<f64>], costs: (i64, i64)) -> i64 {
+    let mut sum: i64 = 0;
+    for problem in problems {
+        let solution = problem.solve();
+
+        if let Some(solution_vector) = solution {
+            let solution_rounded = (solution_vector.get(0).round() as i64, solution_vector.get(1).round() as i64);
+
+            let check_x = (solution_rounded.0 * problem.get(0, 0) as i64 + solution_rounded.1 * problem.get(0, 1) as i64) == problem.get(0, 2) as i64;
+            let check_y = (solution_rounded.0 * problem.get(1, 0) as i64 + solution_rounded.1 * problem.get(1, 1) as i64) == problem.get(1, 2) as i64;
+
+            if check_x && check_y {
+                sum +=  solution_rounded.0 * costs.0 + solution_rounded.1 * costs.1;
+            }
+        }
+    }
+    sum
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Matrix<f64>>, ParseError> {
+    let mut problems = vec![];
+    let mut current = Matrix::new(2, 3, 0.0);
+    let mut new = true;
+    for line in input_string.lines() {
+        new = false;
+        if line.starts_with("Button A:") {
+            let (x, y) = parse_button(input_string, line, "Button A: ", "+")?;
+            current.set(0, 0, x);
+            current.set(1, 0, y);
+        }
+        else if line.starts_with("Button B:") {
+            let (x, y) = parse_button(input_string, line, "Button B: ", "+")?;
+            current.set(0, 1, x);
+            current.set(1, 1, y);
+        }
+        else if line.starts_with("Prize:") {
+            let (x, y) = parse_button(input_string, line, "Prize: ", "=")?;
+            current.set(0, 2, x);
+            current.set(1, 2, y);
+        }
+        else if !line.trim().is_empty() {
+            return Err(ParseError::at(input_string, line, "expected `Button A:`, `Button B:`, `Prize:` or a blank line"));
+        }
+        else {
+            problems.push(current);
+            current = Matrix::new(2, 3, 0.0);
+            new = true;
+        }
+    }
+
+    if !new {
+        problems.push(current);
+    }
+
+    Ok(problems)
+}
+
+fn offset_prizes(problems: &[Matrix<f64>], offset: i64) -> Vec<Matrix<f64>> {
+    problems.iter().map(|problem| {
+        let mut offset_problem = problem.clone();
+        offset_problem.set(0, 2, problem.get(0, 2) + offset as f64);
+        offset_problem.set(1, 2, problem.get(1, 2) + offset as f64);
+        offset_problem
+    }).collect()
+}
+
+fn parse_button(input_string: &str, button_string: &str, prefix: &str, delimiter: &str) -> Result<(f64, f64), ParseError> {
+    let invalid = |fragment| ParseError::at(input_string, fragment, format!("expected `{}X{}<number>, Y{}<number>`", prefix, delimiter, delimiter));
+    let button_content = button_string.strip_prefix(prefix).ok_or_else(|| invalid(button_string))?;
+    let (x_segment, y_segment) = button_content.split_once(", ").ok_or_else(|| invalid(button_content))?;
+
+    let x: f64 = parse_number(input_string, x_segment.split_once(delimiter).ok_or_else(|| invalid(x_segment))?.1)?;
+    let y: f64 = parse_number(input_string, y_segment.split_once(delimiter).ok_or_else(|| invalid(y_segment))?.1)?;
+
+    Ok((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day13;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day13>();
+    }
+
+    examples! {
+        Day13;
+        part1_example: part 1, EXAMPLE => 480;
+        part2_example: part 2, EXAMPLE => 875318608908_i64;
+    }
+}
@@ -0,0 +1,225 @@
+use std::{collections::{HashMap, HashSet}, hash::{DefaultHasher, Hash, Hasher}};
+
+use crate::cancel;
+use crate::geometry::Point;
+use crate::params::{Parameter, Params};
+use crate::parse::{parse_number, ParseError};
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+#[derive(Debug, Clone, Hash)]
+pub struct Robot {
+    position: Point,
+    velocity: Point
+}
+
+#[derive(Hash)]
+struct PatrolMap {
+    size: Point,
+    robots: Vec<Robot>
+}
+
+impl Robot {
+    fn step(&mut self, amount: i64, size: Point) {
+        self.position = (self.position + self.velocity * amount).wrap(size);
+    }
+}
+
+impl PatrolMap {
+    fn new(robots: Vec<Robot>, size: Point) -> PatrolMap {
+        PatrolMap {
+            size,
+            robots
+        }
+    }
+
+    fn step(&mut self, amount: i64) {
+        for robot in self.robots.iter_mut() {
+            robot.step(amount, self.size);
+        }
+    }
+
+    fn count_quadrants(&self) -> i64 {
+        let mut results = [0, 0, 0, 0];
+        let midpoint_x = self.size.0 / 2;
+        let midpoint_y = self.size.1 / 2;
+        for robot in self.robots.iter() {
+            if robot.position.0 < midpoint_x && robot.position.1 < midpoint_y {
+                results[0] += 1;
+            }
+            else if robot.position.0 > midpoint_x && robot.position.1 < midpoint_y {
+                results[1] += 1;
+            }
+            else if robot.position.0 < midpoint_x && robot.position.1 > midpoint_y {
+                results[2] += 1;
+            }
+            else if robot.position.0 > midpoint_x && robot.position.1 > midpoint_y {
+                results[3] += 1;
+            }
+        }
+
+        results[0] * results[1] * results[2] * results[3]
+    }
+
+    fn estimate_entropy(&self) -> i64 {
+        let mut x_buckets: HashMap<i64, Vec<i64>> = HashMap::new();
+        let mut y_buckets: HashMap<i64, Vec<i64>> = HashMap::new();
+
+        for robot in self.robots.iter() {
+            x_buckets.entry(robot.position.0).or_default().push(robot.position.1);
+            y_buckets.entry(robot.position.1).or_default().push(robot.position.0);
+        }
+
+        let mut sum = 0;
+
+        for (_, bucket) in x_buckets.iter_mut() {
+            sum += PatrolMap::estimate_line_entropy(bucket);
+        }
+
+        for (_, bucket) in y_buckets.iter_mut() {
+            sum += PatrolMap::estimate_line_entropy(bucket);
+        }
+
+        sum
+    }
+
+    fn estimate_line_entropy(bucket: &mut [i64]) -> i64 {
+        bucket.sort();
+
+        let mut bucket_iter = bucket.iter();
+        let mut previous = *bucket_iter.next().unwrap();
+        let mut runs = 0;
+        for &value in bucket_iter {
+            if value != previous + 1 {
+                runs += 1;
+            }
+
+            previous = value;
+        }
+        runs
+    }
+}
+
+pub struct Day14;
+
+pub const SOLVER: Solver = Solver::of::<Day14>();
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter::bounded("width", 101, 1, 10000),
+        Parameter::bounded("height", 103, 1, 10000),
+        Parameter::bounded("steps", 100, 0, 1000000000)
+    ];
+
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_robots(input)
+    }
+
+    fn part1(robots: &Self::Input, params: &Params) -> Answer {
+        let mut patrol_map = PatrolMap::new(robots.clone(), Point(params.get("width"), params.get("height")));
+        patrol_map.step(params.get("steps"));
+        patrol_map.count_quadrants().into()
+    }
+
+    fn part2(robots: &Self::Input, params: &Params) -> Answer {
+        let mut patrol_map = PatrolMap::new(robots.clone(), Point(params.get("width"), params.get("height")));
+        let mut visited_states: HashSet<u64> = HashSet::new();
+        let mut running = true;
+        let mut minimum_entropy = 0xFFFFFFFFFFFFFF;
+        let mut minimum_entropy_index = -1;
+
+
+        let mut index = 0;
+        while running && !cancel::is_cancelled() {
+            index += 1;
+            patrol_map.step(1);
+            let mut hasher = DefaultHasher::new();
+            patrol_map.hash(&mut hasher);
+            let state_hash = hasher.finish();
+
+            if visited_states.contains(&state_hash) {
+                running = false;
+            }
+            else {
+                visited_states.insert(state_hash);
+            }
+
+            let estimated_entropy = patrol_map.estimate_entropy();
+
+            if estimated_entropy < minimum_entropy {
+                minimum_entropy = estimated_entropy;
+                minimum_entropy_index = index;
+            }
+        }
+
+        minimum_entropy_index.into()
+    }
+
+    fn generate(random: &mut Random, size: usize, params: &Params) -> Option<String> {
+        let (width, height) = (params.get("width"), params.get("height"));
+
+        let robots: Vec<String> = (0..size).map(|_| {
+            let position = (random.range(0, width - 1), random.range(0, height - 1));
+            let velocity = (random.range(1 - width, width - 1), random.range(1 - height, height - 1));
+            format!("p={},{} v={},{}", position.0, position.1, velocity.0, velocity.1)
+        }).collect();
+
+        Some(robots.join("\n"))
+    }
+}
+
+fn parse_robots(value: &str) -> Result<Vec<Robot>, ParseError> {
+    let mut robots: Vec<Robot> = vec![];
+
+    for line in value.lines() {
+        let invalid = |fragment| ParseError::at(value, fragment, "expected a robot like `p=0,4 v=3,-3`");
+        let (position_segment, velocity_segment) = line.split_once(" ").ok_or_else(|| invalid(line))?;
+        let position = parse_vector(value, position_segment, "p=").ok_or_else(|| invalid(position_segment))??;
+        let velocity = parse_vector(value, velocity_segment, "v=").ok_or_else(|| invalid(velocity_segment))??;
+
+        robots.push(Robot {
+            position,
+            velocity
+        })
+    }
+
+    Ok(robots)
+}
+
+fn parse_vector(value: &str, segment: &str, prefix: &str) -> Option<Result<Point, ParseError>> {
+    let (x, y) = segment.strip_prefix(prefix)?.split_once(",")?;
+
+    Some(parse_number(value, x).and_then(|x| Ok(Point(x, parse_number(value, y)?))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day14;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day14>();
+    }
+
+    examples! {
+        Day14;
+        part1_example: part 1, EXAMPLE, ["width=11", "height=7"] => 12;
+    }
+}
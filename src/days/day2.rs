@@ -0,0 +1,134 @@
+use crate::params::Params;
+use crate::parse::{parse_number, ParseError};
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct Day2;
+
+pub const SOLVER: Solver = Solver::of::<Day2>();
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Answer {
+        let valid_rows: Vec<&Vec<i64>> = input
+        .iter()
+        .filter(|x| row_valid(x, x.len() + 1))
+        .collect();
+
+        valid_rows.len().into()
+    }
+
+    fn part2(input: &Self::Input, _params: &Params) -> Answer {
+        let valid_rows: Vec<&Vec<i64>> = input
+        .iter()
+        .filter(|x| row_valid_with_error(x))
+        .collect();
+
+        valid_rows.len().into()
+    }
+
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let mut reports = vec![];
+
+        for _ in 0..size {
+            let direction = if random.chance(0.5) { 1 } else { -1 };
+            let mut level = random.range(30, 70);
+            let mut levels = vec![];
+
+            for _ in 0..random.range(5, 8) {
+                levels.push(level.to_string());
+                level += direction * random.range(0, 4);
+            }
+
+            reports.push(levels.join(" "));
+        }
+
+        Some(reports.join("\n"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|x| parse_row(input, x))
+        .collect()
+}
+
+fn parse_row(input: &str, row: &str) -> Result<Vec<i64>, ParseError> {
+    row
+        .split(" ")
+        .map(|x| parse_number(input, x))
+        .collect()
+}
+
+fn row_valid_with_error(row: &[i64]) -> bool {
+    let is_already_valid = row_valid(row, row.len() + 1);
+
+    if !is_already_valid {
+        (0..row.len()).any(|i| row_valid(row, i))
+    }
+    else {
+        true
+    }
+}
+
+fn row_valid(row: &[i64], ignore: usize) -> bool {
+    let mut last_sign = 0;
+    let mut current_index = 1;
+    let mut last_index = 0;
+    let row_length = row.len();
+
+    if ignore == 0 {
+        last_index = 1;
+        current_index = 2;
+    }
+
+    while current_index < row_length {
+        if current_index != ignore {
+            let x = *row.get(last_index).unwrap();
+            let y = *row.get(current_index).unwrap();
+            let delta = y - x;
+            let mag = delta.abs();
+            let sign = delta.signum();
+            if !(1..=3).contains(&mag) || (last_sign != 0 && delta.signum() != last_sign) {
+                return false;
+            }
+            last_index = current_index;
+            last_sign = sign;
+        }
+        current_index += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day2;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day2>();
+    }
+
+    examples! {
+        Day2;
+        part1_example: part 1, EXAMPLE => 2;
+        part2_example: part 2, EXAMPLE => 4;
+    }
+}
@@ -1,5 +1,10 @@
+use crate::params::Params;
+use crate::parse::{parse_number, ParseError};
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
 #[derive(Debug)]
-enum Token {
+pub enum Token {
     Number(i64),
     Mul,
     Do,
@@ -17,29 +22,48 @@ enum TokenizerMode {
     Text,
 }
 
-pub fn run(input_path: &str, part: i32) {
-    if part <= 1 {
-        part1(input_path);
+pub struct Day3;
+
+pub const SOLVER: Solver = Solver::of::<Day3>();
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<Token>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        tokenize(input)
+    }
+
+    fn part1(input: &Self::Input, _params: &Params) -> Answer {
+        process(input, true).into()
     }
-    else {
-        part2(input_path);
+
+    fn part2(input: &Self::Input, _params: &Params) -> Answer {
+        process(input, false).into()
     }
-}
 
-fn part1(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let junk = ["mul", "do", "don't", "(", ")", ",", "[", "]", "!", "@", "#", "$", "%", "^", "&", "*", "_", "+", "?", "<", ">", " ", "'", "what", "from", "select", "when"];
+        let mut fragments = vec![];
 
-    println!("{}", process(input_string.as_str(), true));
-}
+        for _ in 0..size {
+            let fragment = match random.below(4) {
+                0 => format!("mul({},{})", random.range(0, 999), random.range(0, 999)),
+                1 => random.choose(&["do()", "don't()"]).to_string(),
+                _ => random.choose(&junk).to_string()
+            };
 
-fn part2(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
+            fragments.push(fragment);
+        }
 
-    println!("{}", process(input_string.as_str(), false));
+        let lines: Vec<String> = fragments.chunks(100).map(|x| x.concat()).collect();
+        Some(lines.join("\n"))
+    }
 }
 
 
-fn tokenize(input: &str) -> Vec<Token> {
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
     let mut tokens = vec![];
     let mut buffer: Vec<char> = vec![];
     let mut mode = TokenizerMode::Unknown;
@@ -50,7 +74,7 @@ fn tokenize(input: &str) -> Vec<Token> {
         let c: char = input_bytes[pointer].into();
         match mode {
             TokenizerMode::Unknown => {
-                if c.is_digit(10) {
+                if c.is_ascii_digit() {
                     mode = TokenizerMode::Number;
                 }
                 else if c.is_alphabetic() {
@@ -74,10 +98,10 @@ fn tokenize(input: &str) -> Vec<Token> {
                 }
             },
             TokenizerMode::Number => {
-                if !c.is_digit(10) {
-                    if buffer.len() > 0 {
-                        let buffer_string: String = buffer.iter().collect();
-                        tokens.push(Token::Number(buffer_string.parse().unwrap()));
+                if !c.is_ascii_digit() {
+                    if !buffer.is_empty() {
+                        let number_text = &input[pointer - buffer.len()..pointer];
+                        tokens.push(Token::Number(parse_number(input, number_text)?));
                         buffer.clear();
                     }
                     mode = TokenizerMode::Unknown
@@ -89,7 +113,7 @@ fn tokenize(input: &str) -> Vec<Token> {
             },
             TokenizerMode::Text => {
                 if !c.is_alphabetic() && c != '\'' {
-                    if buffer.len() > 0 {
+                    if !buffer.is_empty() {
                         let buffer_string: String = buffer.iter().collect();
 
                         let generated_token = if buffer_string.ends_with("mul") {
@@ -117,12 +141,11 @@ fn tokenize(input: &str) -> Vec<Token> {
             }
         }
     }
-    tokens
+    Ok(tokens)
 }
 
-fn process(input: &str, ignore_control: bool) -> i64 {
+fn process(tokens: &[Token], ignore_control: bool) -> i64 {
     let mut sum = 0;
-    let tokens = tokenize(input);
     let mut collect = true;
 
     for window in tokens.windows(6) {
@@ -135,11 +158,10 @@ fn process(input: &str, ignore_control: bool) -> i64 {
             [Token::Dont, _, _, _, _, _] => {
                 collect = false;
             },
-            [Token::Mul, Token::OpenParen, Token::Number(n1), Token::Comma, Token::Number(n2), Token::CloseParen] => {
-                if collect || ignore_control {
+            [Token::Mul, Token::OpenParen, Token::Number(n1), Token::Comma, Token::Number(n2), Token::CloseParen]
+                if (collect || ignore_control) => {
                     sum += n1 * n2;
-                }
-            },
+                },
             _ => {
 
             }
@@ -147,4 +169,23 @@ fn process(input: &str, ignore_control: bool) -> i64 {
     }
 
     sum
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day3;
+
+    const EXAMPLE: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day3>();
+    }
+
+    examples! {
+        Day3;
+        part1_example: part 1, EXAMPLE => 161;
+        part2_example: part 2, EXAMPLE => 48;
+    }
+}
@@ -0,0 +1,130 @@
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct WordSearch {
+    width: i32,
+    height: i32,
+    content: String
+}
+
+static DIRECTIONS: [(i32, i32); 8] = [(1, 1), (1, 0), (1, -1), (0, 1), (0, -1), (-1, 1), (-1, 0), (-1, -1)];
+
+impl WordSearch {
+    fn get(&self, x: i32, y: i32) -> Option<char> {
+        if x >= 0 && x < self.width && y >= 0 && y < self.height {
+            let index = y * (self.width + 1) + x;
+            Some(self.content.as_bytes()[index as usize] as char)
+        }
+        else {
+            None
+        }
+    }
+
+    fn get_sequence(&self, sx: i32, sy: i32, ox: i32, oy: i32, from: i32, to: i32) -> impl Iterator<Item = char> + '_ {
+        (from..to)
+            .map(move |index| self.get(sx + ox * index, sy + oy * index))
+            .take_while(|mc| mc.is_some())
+            .map(|mc| mc.unwrap())
+    }
+}
+
+pub struct Day4;
+
+pub const SOLVER: Solver = Solver::of::<Day4>();
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = WordSearch;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input.to_string())
+    }
+
+    fn part1(grid: &Self::Input, _params: &Params) -> Answer {
+        let mut sum = 0;
+
+        for x in 0..grid.width {
+            for y in 0..grid.height {
+                if grid.get(x, y) == Some('X') {
+                    for (dx, dy) in DIRECTIONS {
+                        let s = grid.get_sequence(x, y, dx, dy, 1, 4);
+                        if s.eq("MAS".chars()) {
+                            sum += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+
+        sum.into()
+    }
+
+    fn part2(grid: &Self::Input, _params: &Params) -> Answer {
+        let mut sum = 0;
+
+        for x in 0..grid.width {
+            for y in 0..grid.height {
+                if grid.get(x, y) == Some('A') {
+                    let s1 = grid.get_sequence(x, y, 1, 1, -1, 2);
+                    let s2 = grid.get_sequence(x, y, 1, -1, -1, 2);
+                    let s3 = grid.get_sequence(x, y, 1, 1, -1, 2);
+                    let s4 = grid.get_sequence(x, y, 1, -1, -1, 2);
+                    if (s1.eq("MAS".chars()) || s3.eq("SAM".chars())) && (s2.eq("MAS".chars()) || s4.eq("SAM".chars())) {
+                        sum += 1
+                    }
+                }
+            }
+        }
+
+
+        sum.into()
+    }
+}
+
+fn parse_input(input: String) -> Result<WordSearch, ParseError> {
+    let width = input.find('\n').unwrap_or(input.len());
+
+    if width == 0 {
+        return Err(ParseError::at_offset(&input, 0, 0, "expected at least one letter on the first row"));
+    }
+
+    let height = input.lines().count();
+
+    if let Some(row) = input.lines().find(|x| x.len() != width) {
+        return Err(ParseError::at(&input, row, format!("expected every row to have {} letters", width)));
+    }
+
+    let too_large = || ParseError::at_end(&input, "word search is too large");
+
+    Ok(WordSearch {
+        width: i32::try_from(width).map_err(|_| too_large())?,
+        height: i32::try_from(height).map_err(|_| too_large())?,
+        content: input
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day4;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    examples! {
+        Day4;
+        part1_example: part 1, EXAMPLE => 18;
+        part2_example: part 2, EXAMPLE => 9;
+    }
+}
@@ -0,0 +1,126 @@
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::params::Params;
+use crate::parse::{parse_number, ParseError};
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct Manual {
+    ordering_rules: HashMap<(i64, i64), Ordering>,
+    updates: Vec<Vec<i64>>
+}
+
+pub struct Day5;
+
+pub const SOLVER: Solver = Solver::of::<Day5>();
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Manual;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(manual: &Self::Input, _params: &Params) -> Answer {
+        let mut sum = 0;
+
+        for update in manual.updates.iter() {
+            let mut sorted_update = update.to_vec();
+            sorted_update.sort_by(|x, y| *manual.ordering_rules.get(&(*x, *y)).unwrap_or(&Ordering::Equal));
+
+            if sorted_update.iter().eq(update.iter()) {
+                let middle_index = sorted_update.len() / 2;
+                sum += sorted_update[middle_index];
+            }
+
+        }
+
+        sum.into()
+    }
+
+    fn part2(manual: &Self::Input, _params: &Params) -> Answer {
+        let mut sum = 0;
+
+        for update in manual.updates.iter() {
+            let mut sorted_update = update.to_vec();
+            sorted_update.sort_by(|x, y| *manual.ordering_rules.get(&(*x, *y)).unwrap_or(&Ordering::Equal));
+
+            if !sorted_update.iter().eq(update.iter()) {
+                let middle_index = sorted_update.len() / 2;
+                sum += sorted_update[middle_index];
+            }
+
+        }
+
+        sum.into()
+    }
+}
+
+fn parse_input(input: &str) -> Result<Manual, ParseError> {
+    let mut parsing_rules = true;
+    let mut ordering_rules: HashMap<(i64, i64), Ordering> = HashMap::new();
+    let mut updates = vec![];
+    for line in input.lines() {
+        if line.is_empty() {
+            parsing_rules = false
+        }
+        else if parsing_rules {
+            let (first, second) = line.split_once("|").ok_or_else(|| ParseError::at(input, line, "expected an ordering rule like `47|53`"))?;
+            let x: i64 = parse_number(input, first)?;
+            let y: i64 = parse_number(input, second)?;
+            ordering_rules.insert((x, y), Ordering::Less);
+            ordering_rules.insert((y, x), Ordering::Greater);
+        } else {
+            let update = line.split(",").map(|x| parse_number(input, x)).collect::<Result<Vec<i64>, ParseError>>()?;
+            updates.push(update);
+        }
+    }
+
+    Ok(Manual {
+        ordering_rules,
+        updates
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day5;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    examples! {
+        Day5;
+        part1_example: part 1, EXAMPLE => 143;
+        part2_example: part 2, EXAMPLE => 123;
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+
+use crate::cancel;
+use crate::geometry::{Direction, Grid, Point, RaggedRows};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+const CANCEL_POLL_INTERVAL: usize = 4096;
+
+struct VisitedSet {
+    width: usize,
+    content: Box<[u8]>
+}
+
+pub struct PatrolMap {
+    size: Point,
+    start_position: Point,
+    obstacles: VisitedSet
+}
+
+struct PatrolMapIterator<'a> {
+    patrol_map: &'a PatrolMap,
+    position: Point,
+    direction: Direction,
+    overlay: Point
+}
+
+impl <'a> Iterator  for PatrolMapIterator<'a> {
+    type Item = (Point, Direction, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position.in_bounds(self.patrol_map.size) {
+            let old_position = self.position;
+
+            let mut changed_direction = false;
+
+            let mut new_position = self.position + self.direction;
+            let mut turns = 0;
+
+            while new_position.in_bounds(self.patrol_map.size) && (self.patrol_map.obstacles.is_visited(new_position, Direction::North) || new_position == self.overlay) {
+                if turns == 4 {
+                    new_position = self.position;
+                    break;
+                }
+
+                self.direction = self.direction.turn_right();
+                new_position = self.position + self.direction;
+                changed_direction = true;
+                turns += 1;
+            }
+
+            self.position = new_position;
+
+            Some((old_position, self.direction, changed_direction))
+        }
+        else {
+            None
+        }
+    }
+}
+
+
+impl From<&PatrolMap> for VisitedSet {
+    fn from(value: &PatrolMap) -> Self {
+        VisitedSet::new(value.size)
+    }
+}
+
+impl VisitedSet {
+    fn new(size: Point) -> VisitedSet {
+        VisitedSet {
+            width: size.0 as usize,
+            content: vec![0; size.area() as usize].into_boxed_slice()
+        }
+    }
+
+    fn new_from_obstacles(size: Point, obstacle_set: &HashSet<Point>) -> VisitedSet {
+        let mut result = VisitedSet::new(size);
+
+        for &obstacle in obstacle_set {
+            result.visit(obstacle, Direction::North);
+        }
+
+        result
+    }
+
+    fn visit(&mut self, position: Point, direction: Direction) {
+        let index = position.1 as usize * self.width + position.0 as usize;
+        self.content[index] |= direction.to_bit_mask();
+    }
+
+    fn is_visited(&self, position: Point, direction: Direction) -> bool {
+        let index = position.1 as usize * self.width + position.0 as usize;
+        self.content[index] & direction.to_bit_mask() > 0
+    }
+}
+
+impl TryFrom<&str> for PatrolMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut obstacle_set = HashSet::new();
+        let mut start_position = None;
+
+        let grid = Grid::parse(input, RaggedRows::Reject, |position, c| match c {
+            '#' => {
+                obstacle_set.insert(position);
+                Ok(c)
+            }
+            '^' if start_position.is_some() => Err("expected only one guard `^`"),
+            '^' => {
+                start_position = Some(position);
+                Ok(c)
+            }
+            '.' => Ok(c),
+            _ => Err("expected `.`, `#` or `^`")
+        })?;
+
+        let size = grid.size;
+        let start_position = start_position.ok_or_else(|| ParseError::at_end(input, "expected a guard `^` somewhere on the map"))?;
+
+        Ok(PatrolMap {
+            size,
+            start_position,
+            obstacles: VisitedSet::new_from_obstacles(size, &obstacle_set)
+        })
+    }
+}
+
+impl PatrolMap {
+    fn path<'a>(&'a self, start_position: Point, start_direction: Direction) -> PatrolMapIterator<'a> {
+        PatrolMapIterator {
+            patrol_map: self,
+            position: start_position,
+            direction: start_direction,
+            overlay: Point(-100, -100)
+        }
+    }
+
+    fn exits(&self) -> bool {
+        let mut visited_set = VisitedSet::from(self);
+
+        for (point, direction, _) in self.path(self.start_position, Direction::North) {
+            if visited_set.is_visited(point, direction) {
+                return false;
+            }
+
+            visited_set.visit(point, direction);
+        }
+
+        true
+    }
+
+    fn visited_points(&self) -> HashSet<Point> {
+        self.path(self.start_position, Direction::North)
+            .enumerate()
+            .take_while(|(step, _)| step % CANCEL_POLL_INTERVAL != 0 || !cancel::is_cancelled())
+            .map(|(_, x)| x.0)
+            .collect()
+    }
+
+    fn path_with_overlay<'a>(&'a self, start_position: Point, start_direction: Direction, overlay: Point) -> PatrolMapIterator<'a> {
+        PatrolMapIterator {
+            patrol_map: self,
+            position: start_position,
+            direction: start_direction,
+            overlay
+        }
+    }
+}
+
+
+pub struct Day6;
+
+pub const SOLVER: Solver = Solver::of::<Day6>();
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = PatrolMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        PatrolMap::try_from(input)
+    }
+
+    fn part1(patrol_map: &Self::Input, _params: &Params) -> Answer {
+        patrol_map.visited_points().len().into()
+    }
+
+    fn part2(patrol_map: &Self::Input, _params: &Params) -> Answer {
+        let point_set = patrol_map.visited_points();
+
+        let mut non_terminating_count = 0;
+
+        for path_point in point_set {
+            if cancel::is_cancelled() {
+                break;
+            }
+
+            if path_point != patrol_map.start_position {
+                let mut visited_set= VisitedSet::from(patrol_map);
+                for (point, direction, changed_direction) in patrol_map.path_with_overlay(patrol_map.start_position, Direction::North, path_point) {
+                    if changed_direction {
+                        if visited_set.is_visited(point, direction) {
+                            non_terminating_count += 1;
+                            break
+                        }
+
+                        visited_set.visit(point, direction);
+                    }
+                }
+            }
+        }
+
+        non_terminating_count.into()
+    }
+
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let size = size.max(1) as u64;
+
+        loop {
+            let start = (random.below(size), random.below(size));
+            let mut rows = vec![];
+
+            for y in 0..size {
+                let mut row = String::new();
+
+                for x in 0..size {
+                    if (x, y) == start {
+                        row.push('^');
+                    }
+                    else if x.abs_diff(start.0) + y.abs_diff(start.1) > 1 && random.chance(0.05) {
+                        row.push('#');
+                    }
+                    else {
+                        row.push('.');
+                    }
+                }
+
+                rows.push(row);
+            }
+
+            let input = rows.join("\n");
+
+            if PatrolMap::try_from(input.as_str()).is_ok_and(|x| x.exits()) {
+                return Some(input);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day6;
+    use crate::solver::Solution;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn parse_rejects_second_guard() {
+        let error = Day6::parse("..^\n^..").err().unwrap();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 1, "^"));
+    }
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day6>();
+    }
+
+    #[test]
+    fn generated_input_solves() {
+        crate::solver::check_generated_part::<Day6>(1, 100);
+    }
+
+    examples! {
+        Day6;
+        part1_example: part 1, EXAMPLE => 41;
+        part2_example: part 2, EXAMPLE => 6;
+    }
+}
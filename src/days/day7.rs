@@ -0,0 +1,118 @@
+use crate::params::Params;
+use crate::parse::{parse_number, ParseError};
+use crate::solver::{Answer, Solution, Solver};
+
+#[derive(Debug)]
+pub struct CalibrationEquation {
+    target: u64,
+    operands: Box<[u64]>
+}
+
+pub struct Day7;
+
+pub const SOLVER: Solver = Solver::of::<Day7>();
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<CalibrationEquation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(equations: &Self::Input, _params: &Params) -> Answer {
+        let result = equations.iter().map(evaluate_equation).sum::<u64>();
+        result.into()
+    }
+
+    fn part2(equations: &Self::Input, _params: &Params) -> Answer {
+        let result = equations.iter().map(evaluate_equation_with_concatenation).sum::<u64>();
+        result.into()
+    }
+}
+
+fn integer_concatenate(x: u64, y: u64) -> u64 {
+    let digits = y.ilog10();
+    x * 10u64.pow(digits + 1) + y
+}
+
+fn evaluate_equation(equation: &CalibrationEquation) -> u64 {
+    let bit_count = 1 << (equation.operands.len() - 1);
+    evaluate_equation_with_concatenation_mask(equation, bit_count, 0)
+}
+
+fn evaluate_equation_with_concatenation(equation: &CalibrationEquation) -> u64 {
+    let bit_count = 1 << (equation.operands.len() - 1);
+
+    for concatenation_mask in 0..=bit_count {
+        let result = evaluate_equation_with_concatenation_mask(equation, bit_count, concatenation_mask);
+        if result > 0 {
+            return result;
+        }
+    }
+
+    0
+}
+
+fn evaluate_equation_with_concatenation_mask(equation: &CalibrationEquation, bit_count: i32, concatenation_mask: i32) -> u64 {
+    for operator_mask in (0..=bit_count).filter(|x| x & concatenation_mask == 0) {
+        let first = equation.operands[0];
+        let result = equation.operands
+            .iter()
+            .skip(1)
+            .enumerate()
+            .fold(first, |v, (i, &x)| if ((1 << i) & concatenation_mask) > 0 {
+                integer_concatenate(v, x)
+            } else if ((1 << i) & operator_mask) > 0 {
+                v * x
+            } else {
+                v + x
+            });
+
+        if result == equation.target {
+            return equation.target;
+        }
+    }
+
+    0
+}
+
+fn parse_input(input: &str) -> Result<Vec<CalibrationEquation>, ParseError> {
+    let mut equations = vec![];
+
+    for line in input.lines() {
+        let (target_text, operands_text) = line.split_once(':').ok_or_else(|| ParseError::at(input, line, "expected an equation like `190: 10 19`"))?;
+
+        let target = parse_number(input, target_text)?;
+        let operands: Box<[u64]> = operands_text.trim().split(" ").map(|x| parse_number(input, x)).collect::<Result<Box<[u64]>, ParseError>>()?;
+        equations.push(CalibrationEquation {
+            target,
+            operands
+        });
+    }
+
+    Ok(equations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    examples! {
+        Day7;
+        part1_example: part 1, EXAMPLE => 3749;
+        part2_example: part 2, EXAMPLE => 11387;
+    }
+}
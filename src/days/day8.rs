@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::geometry::{Grid, Point, RaggedRows};
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solver::{Answer, Solution, Solver};
+
+pub struct CityMap {
+    size: Point,
+    antennae: HashMap<char, Vec<Point>>
+}
+
+impl TryFrom<&str> for CityMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut antennae: HashMap<char, Vec<Point>> = HashMap::new();
+
+        let grid = Grid::parse(input, RaggedRows::Reject, |position, c| match c {
+            '.' => Ok(c),
+            c if c.is_ascii_alphanumeric() => {
+                antennae.entry(c).or_default().push(position);
+                Ok(c)
+            }
+            _ => Err("expected `.` or an antenna frequency")
+        })?;
+
+        Ok(CityMap {
+            size: grid.size,
+            antennae
+        })
+    }
+}
+
+impl CityMap {
+    fn get_antinodes(&self, bounded: bool) -> impl Iterator<Item = Point> + '_ {
+        self.antennae.values()
+            .filter(|av| av.len() > 1)
+            .flat_map(move |a| {
+                let mut result: Vec<Point> = vec![];
+
+                if !bounded && a.len() > 1 {
+                    for &antenna in a {
+                        result.push(antenna);
+                    }
+                }
+
+                for (&antenna1, &antenna2) in get_pairs(a) {
+                    if antenna1 != antenna2 {
+                        let a1delta = antenna2 - antenna1;
+                        let a2delta = antenna1 - antenna2;
+                        let mut antinode1 = antenna2 + a1delta;
+                        let mut antinode2 = antenna1 + a2delta;
+
+                        let mut evaluated1 = false;
+                        while antinode1.in_bounds(self.size) && (!bounded || !evaluated1) {
+                            result.push(antinode1);
+                            antinode1 = antinode1 + a1delta;
+                            evaluated1 = true;
+                        }
+
+                        let mut evaluated2 = false;
+                        while antinode2.in_bounds(self.size) && (!bounded || !evaluated2) {
+                            result.push(antinode2);
+                            antinode2 = antinode2 + a2delta;
+                            evaluated2 = true;
+                        }
+                    }
+                }
+
+                result
+            })
+    }
+}
+
+pub struct Day8;
+
+pub const SOLVER: Solver = Solver::of::<Day8>();
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = CityMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        CityMap::try_from(input)
+    }
+
+    fn part1(city_map: &Self::Input, _params: &Params) -> Answer {
+        let unique_antinodes: HashSet<Point> = city_map.get_antinodes(true).collect();
+        unique_antinodes.len().into()
+    }
+
+    fn part2(city_map: &Self::Input, _params: &Params) -> Answer {
+        let unique_antinodes: HashSet<Point> = city_map.get_antinodes(false).collect();
+        unique_antinodes.len().into()
+    }
+}
+
+fn get_pairs<T>(input: &[T]) -> impl Iterator<Item = (&T, &T)> + '_ {
+    input.iter()
+        .enumerate()
+        .flat_map(|(i, x)| {
+            input.iter().skip(i + 1).map(move |y| (x, y))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day8;
+    use crate::solver::Solution;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        assert!(Day8::parse("..a.\n..").is_err());
+    }
+
+    examples! {
+        Day8;
+        part1_example: part 1, EXAMPLE => 14;
+        part2_example: part 2, EXAMPLE => 34;
+        part1_single_row: part 1, "..a.a....." => 2;
+    }
+}
@@ -1,5 +1,12 @@
 use std::fmt::Display;
 
+use crate::params::Params;
+use crate::parse::ParseError;
+use crate::random::Random;
+use crate::solver::{Answer, Solution, Solver};
+
+const FIT_BUCKETS: usize = 10;
+
 #[derive(Debug, Clone, Copy)]
 struct DiskFile {
     id: u64,
@@ -15,7 +22,7 @@ struct DiskBlock {
 #[derive(Debug)]
 struct DiskMap {
     blocks: Vec<DiskBlock>,
-    fit_map: [Vec<usize>; 10]
+    fit_map: Vec<Vec<usize>>
 }
 
 impl DiskFile {
@@ -56,7 +63,7 @@ impl DiskBlock {
     }
 
     fn checksum_serialize(&self) -> impl Iterator<Item = u64> + '_ {
-        return self.files.iter().flat_map(|v| v.checksum_serialize()).chain((0..self.free_space).map(|_| 0));
+        self.files.iter().flat_map(|v| v.checksum_serialize()).chain((0..self.free_space).map(|_| 0))
     }
 }
 
@@ -78,11 +85,9 @@ impl DiskMap {
             let source_block = &self.blocks[source_pointer];
 
             if !source_block.is_free() {
-                let maybe_dest_pointer = self.fit_map[source_block.get_size() as usize].last();
-
-                if maybe_dest_pointer.is_some() {
-                    let &dest_pointer = maybe_dest_pointer.unwrap();
+                let maybe_dest_pointer = self.fit_map.get(source_block.get_size()).and_then(|x| x.last());
 
+                if let Some(&dest_pointer) = maybe_dest_pointer {
                     if dest_pointer < source_pointer {
                         self.transfer(source_pointer, dest_pointer);
                     }
@@ -95,50 +100,68 @@ impl DiskMap {
 
     fn transfer(&mut self, source_pointer: usize, dest_pointer: usize) {
         let original_free_space = self.blocks[dest_pointer].free_space;
-        let file = self.blocks[source_pointer as usize].remove();
+        let file = self.blocks[source_pointer].remove();
         self.blocks[dest_pointer].insert(file);
         let new_free_space = self.blocks[dest_pointer].free_space;
 
-        for fit_bucket_index in (new_free_space + 1)..=original_free_space {
+        for fit_bucket_index in (new_free_space + 1)..=original_free_space.min(self.fit_map.len() as u64 - 1) {
             let clear_index = self.fit_map[fit_bucket_index as usize].iter().enumerate().rev().find(|(_, &v)| v == dest_pointer).unwrap().0;
             self.fit_map[fit_bucket_index as usize].remove(clear_index);
         }
     }
 
     fn checksum(&self) -> u64 {
-        self.blocks.iter().flat_map(|v| v.checksum_serialize()).enumerate().map(|(i, id)| i as u64 * id).fold(0, |a, b| a + b)
+        self.blocks.iter().flat_map(|v| v.checksum_serialize()).enumerate().map(|(i, id)| i as u64 * id).sum()
     }
 }
 
-pub fn run(input_path: &str, part: i32) {
-    if part <= 1 {
-        part1(input_path);
+pub struct Day9;
+
+pub const SOLVER: Solver = Solver::of::<Day9>();
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
-    else {
-        part2(input_path);
+
+    fn part1(sizes: &Self::Input, _params: &Params) -> Answer {
+        let mut disk_map = build_disk_map(sizes, true);
+        disk_map.compact();
+        disk_map.checksum().into()
+    }
+
+    fn part2(sizes: &Self::Input, _params: &Params) -> Answer {
+        let mut disk_map = build_disk_map(sizes, false);
+        disk_map.compact();
+        disk_map.checksum().into()
     }
-}
 
-fn part1(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
-    let mut disk_map = parse_input(input_string.as_str(), true);
-    disk_map.compact();
-    println!("{}", disk_map.checksum());
+    fn generate(random: &mut Random, size: usize, _params: &Params) -> Option<String> {
+        let digits = (0..(size | 1)).map(|i| {
+            let digit = if i % 2 == 0 { random.range(1, 9) } else { random.range(0, 9) };
+            char::from_digit(digit as u32, 10).unwrap()
+        });
+
+        Some(digits.collect())
+    }
 }
 
-fn part2(input_path: &str) {
-    let input_string = std::fs::read_to_string(input_path).unwrap();
-    let mut disk_map = parse_input(input_string.as_str(), false);
-    disk_map.compact();
-    println!("{}", disk_map.checksum());
+fn parse_input(input_string: &str) -> Result<Vec<u64>, ParseError> {
+    input_string
+        .char_indices()
+        .map(|(offset, c)| c.to_digit(10).map(|x| x as u64).ok_or_else(|| ParseError::at_offset(input_string, offset, c.len_utf8(), "expected a digit")))
+        .collect()
 }
 
-fn parse_input(input_string: &str, split_files: bool) -> DiskMap {
-    let mut fit_map: [Vec<usize>; 10] = [vec![], vec![], vec![], vec![], vec![], vec![], vec![], vec![], vec![], vec![]];
+fn build_disk_map(sizes: &[u64], split_files: bool) -> DiskMap {
+    let mut fit_map: Vec<Vec<usize>> = vec![vec![]; FIT_BUCKETS];
     let mut blocks = vec![];
 
-    for (i, c) in input_string.chars().enumerate() {
-        let size = c.to_digit(10).unwrap() as u64;
+    for (i, &size) in sizes.iter().enumerate() {
 
         if i % 2 == 0 {
             if split_files {
@@ -179,19 +202,38 @@ fn parse_input(input_string: &str, split_files: bool) -> DiskMap {
                     free_space: size
                 });
 
-                for fit_bucket in 1..=size {
+                for fit_bucket in 1..=size.min(FIT_BUCKETS as u64 - 1) {
                     fit_map[fit_bucket as usize].push(blocks.len() - 1);
                 }
             }
         }
     }
 
-    for fit_bucket in 1..10 {
-        fit_map[fit_bucket].reverse();
+    for fit_bucket in fit_map.iter_mut().skip(1) {
+        fit_bucket.reverse();
     }
 
     DiskMap {
         blocks,
         fit_map
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day9;
+
+    const EXAMPLE: &str = "\
+2333133121414131402";
+
+    #[test]
+    fn generated_input_parses() {
+        crate::solver::check_generated::<Day9>();
+    }
+
+    examples! {
+        Day9;
+        part1_example: part 1, EXAMPLE => 1928;
+        part2_example: part 2, EXAMPLE => 2858;
+    }
+}
@@ -1,4 +1,6 @@
-use std::{collections::HashSet, fmt::Display, ops::{Add, Div, Mul, Rem, Sub}};
+use std::{fmt::Display, fs::{self, File}, io::{self, BufWriter, Write}, ops::{Add, Div, Index, IndexMut, Mul, Rem, Sub}, path::{Path, PathBuf}};
+
+use crate::parse::ParseError;
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum Direction {
@@ -16,6 +18,18 @@ pub struct Grid<T> {
     content: Box<[T]>
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaggedRows<T> {
+    Reject,
+    Pad(T)
+}
+
+pub struct FrameWriter {
+    directory: PathBuf,
+    prefix: String,
+    next_frame: usize
+}
+
 #[derive(Debug)]
 pub struct DirectionMap<T> {
     north: T,
@@ -43,6 +57,15 @@ impl Direction {
         }
     }
 
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<'
+        }
+    }
+
     pub fn to_bit_mask(self) -> u8 {
         match self {
             Direction::North => 1,
@@ -146,11 +169,11 @@ impl Point {
         let mut wrapped = self % boundary;
 
         if wrapped.0 < 0 {
-            wrapped.0 = boundary.0 + wrapped.0;
+            wrapped.0 += boundary.0;
         }
 
         if wrapped.1 < 0 {
-            wrapped.1 = boundary.1 + wrapped.1;
+            wrapped.1 += boundary.1;
         }
 
         wrapped
@@ -165,36 +188,47 @@ impl Point {
     }
 }
 
-impl <'a, T: Into<&'a str>> From<T> for Grid<char> {
-    fn from(value: T) -> Self {
-        let mut content = vec![];
-        let mut width = 0;
-        let string_content: &str = value.into();
-        let mut x = 0;
-        let mut y = 0;
-
-        for c in string_content.chars() {
-            match c {
-                '\n' => {
-                    width = width.max(x);
-                    x = 0;
-                    y += 1;
-                }
-                v => {
-                    content.push(v);
-                    x += 1;
-                }
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
             }
+
+            write!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Grid<char> {
+    pub fn draw_points(&mut self, points: impl IntoIterator<Item = Point>, marker: char) -> &mut Self {
+        for point in points {
+            self.set(point, marker);
         }
 
-        let height = y + 1;
+        self
+    }
 
-        let slice_content = content.into_boxed_slice();
+    pub fn draw_path(&mut self, path: impl IntoIterator<Item = (Point, Direction)>) -> &mut Self {
+        const ARROWS: [char; 4] = ['^', '>', 'v', '<'];
 
-        Grid {
-            size: Point(width, height),
-            content: slice_content
+        for (point, direction) in path {
+            if let Some(cell) = self.get_mut(point) {
+                *cell = if ARROWS.contains(cell) && *cell != direction.arrow() { '+' } else { direction.arrow() };
+            }
         }
+
+        self
+    }
+}
+
+impl TryFrom<&str> for Grid<char> {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Grid::parse(input, RaggedRows::Reject, |_, c| Ok(c))
     }
 }
 
@@ -209,7 +243,46 @@ impl <T> Grid<T> {
         index.map(|x| (position, &self.content[x]))
     }
 
-    pub fn map<T2>(&self, f: fn(&T) -> T2) -> Grid<T2> {
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        let index = self.calculate_index(position);
+        index.map(|x| &mut self.content[x])
+    }
+
+    pub fn set(&mut self, position: Point, value: T) -> Option<T> {
+        self.get_mut(position).map(|x| std::mem::replace(x, value))
+    }
+
+    pub fn swap(&mut self, a: Point, b: Point) {
+        let a = self.expect_index(a);
+        let b = self.expect_index(b);
+        self.content.swap(a, b);
+    }
+
+    pub fn row(&self, y: i64) -> Option<&[T]> {
+        let start = self.calculate_index(Point(0, y))?;
+        Some(&self.content[start..start + self.size.0 as usize])
+    }
+
+    pub fn row_mut(&mut self, y: i64) -> Option<&mut [T]> {
+        let start = self.calculate_index(Point(0, y))?;
+        Some(&mut self.content[start..start + self.size.0 as usize])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.content.chunks(self.size.0.max(1) as usize)
+    }
+
+    pub fn column(&self, x: i64) -> Option<impl Iterator<Item = &T> + '_> {
+        let start = self.calculate_index(Point(x, 0))?;
+        Some(self.content[start..].iter().step_by(self.size.0 as usize))
+    }
+
+    pub fn column_mut(&mut self, x: i64) -> Option<impl Iterator<Item = &mut T> + '_> {
+        let start = self.calculate_index(Point(x, 0))?;
+        Some(self.content[start..].iter_mut().step_by(self.size.0 as usize))
+    }
+
+    pub fn map<T2>(&self, f: impl FnMut(&T) -> T2) -> Grid<T2> {
         let new_content: Box<[T2]> = self.content.iter().map(f).collect();
         Grid {
             size: self.size,
@@ -217,17 +290,82 @@ impl <T> Grid<T> {
         }
     }
 
-    pub fn find_all(&self, f: fn(&T) -> bool) -> impl Iterator<Item = (Point, &T)> + '_ {
-        self.content.iter().enumerate().filter(move |(_, v)| f(v)).map(|(i, v)| (Point(i as i64 % self.size.0, i as i64 / self.size.0), v))
+    pub fn map_with_position<T2>(&self, mut f: impl FnMut(Point, &T) -> T2) -> Grid<T2> {
+        let new_content: Box<[T2]> = self.iter().map(|(p, v)| f(p, v)).collect();
+        Grid {
+            size: self.size,
+            content: new_content
+        }
+    }
+
+    pub fn zip_with<U, V>(&self, other: &Grid<U>, mut f: impl FnMut(&T, &U) -> V) -> Option<Grid<V>> {
+        if self.size != other.size {
+            return None;
+        }
+
+        let new_content: Box<[V]> = self.content.iter().zip(other.content.iter()).map(|(a, b)| f(a, b)).collect();
+        Some(Grid {
+            size: self.size,
+            content: new_content
+        })
+    }
+
+    pub fn find_all<'a>(&'a self, mut f: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        self.iter().filter(move |(_, v)| f(v))
+    }
+
+    pub fn filter_map<'a, U>(&'a self, mut f: impl FnMut(Point, &T) -> Option<U> + 'a) -> impl Iterator<Item = (Point, U)> + 'a {
+        self.iter().filter_map(move |(p, v)| f(p, v).map(|x| (p, x)))
+    }
+
+    pub fn count_where(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.content.iter().filter(|x| f(x)).count()
+    }
+
+    pub fn render(&self, f: impl FnMut(&T) -> char) -> Grid<char> {
+        self.map(f)
+    }
+
+    pub fn write_ppm(&self, writer: &mut impl Write, mut f: impl FnMut(&T) -> [u8; 3]) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.size.0, self.size.1)?;
+
+        for value in self.content.iter() {
+            writer.write_all(&f(value))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_pgm(&self, writer: &mut impl Write, mut f: impl FnMut(&T) -> u8) -> io::Result<()> {
+        write!(writer, "P5\n{} {}\n255\n", self.size.0, self.size.1)?;
+        let pixels: Vec<u8> = self.content.iter().map(&mut f).collect();
+        writer.write_all(&pixels)
+    }
+
+    pub fn save_ppm(&self, path: impl AsRef<Path>, f: impl FnMut(&T) -> [u8; 3]) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut writer, f)?;
+        writer.flush()
+    }
+
+    pub fn save_pgm(&self, path: impl AsRef<Path>, f: impl FnMut(&T) -> u8) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_pgm(&mut writer, f)?;
+        writer.flush()
     }
 
     pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
-        self.content.iter().enumerate().map(|(i, v)| (Point(i as i64 % self.size.0, i as i64 / self.size.0), v))
+        self.content.iter().enumerate().map(|(i, v)| (self.position(i), v))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        let width = self.size.0;
+        self.content.iter_mut().enumerate().map(move |(i, v)| (Point(i as i64 % width, i as i64 / width), v))
     }
 
     pub fn flood_fill(&self, position: Point, f: impl Fn(&T) -> bool) -> Vec<(Point, DirectionMap<bool>)> {
         let mut result: Vec<(Point, DirectionMap<bool>)> = vec![];
-        let mut visited: HashSet<Point> = HashSet::new();
+        let mut visited = Grid::new(self.size, false);
         let mut visit_stack = vec![position];
 
         // Special case for initial not matching flood fill condition
@@ -237,10 +375,8 @@ impl <T> Grid<T> {
             return result;
         }
 
-        while !visit_stack.is_empty() {
-            let visit_position = visit_stack.pop().unwrap();
-
-            if visit_position.in_bounds(self.size) && !visited.contains(&visit_position) {
+        while let Some(visit_position) = visit_stack.pop() {
+            if visit_position.in_bounds(self.size) && !visited[visit_position] {
                 let north_neighbor = self.get_with_index(visit_position + Direction::North);
                 let east_neighbor = self.get_with_index(visit_position + Direction::East);
                 let south_neighbor = self.get_with_index(visit_position + Direction::South);
@@ -268,7 +404,7 @@ impl <T> Grid<T> {
                 }
 
                 result.push((visit_position, direction_map));
-                visited.insert(visit_position);
+                visited[visit_position] = true;
             }
         }
 
@@ -283,6 +419,29 @@ impl <T> Grid<T> {
             None
         }
     }
+
+    fn position(&self, index: usize) -> Point {
+        Point(index as i64 % self.size.0, index as i64 / self.size.0)
+    }
+
+    fn expect_index(&self, position: Point) -> usize {
+        self.calculate_index(position).unwrap_or_else(|| panic!("position {} is outside a grid of size {}", position, self.size))
+    }
+}
+
+impl <T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &Self::Output {
+        &self.content[self.expect_index(position)]
+    }
+}
+
+impl <T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        let index = self.expect_index(position);
+        &mut self.content[index]
+    }
 }
 
 impl <T: Clone> Grid<T> {
@@ -293,6 +452,88 @@ impl <T: Clone> Grid<T> {
             content: content.into_boxed_slice()
         }
     }
+
+    pub fn fill(&mut self, value: T) {
+        self.content.fill(value);
+    }
+
+    pub fn scaled(&self, factor: usize) -> Grid<T> {
+        let factor = factor.max(1) as i64;
+        let size = self.size * factor;
+        let content: Box<[T]> = (0..size.area()).map(|i| self[Point(i % size.0 / factor, i / size.0 / factor)].clone()).collect();
+
+        Grid {
+            size,
+            content
+        }
+    }
+
+    pub fn parse(input: &str, ragged: RaggedRows<T>, mut f: impl FnMut(Point, char) -> Result<T, &'static str>) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = match ragged {
+            RaggedRows::Reject => lines.first().map_or(0, |x| x.chars().count()),
+            RaggedRows::Pad(_) => lines.iter().map(|x| x.chars().count()).max().unwrap_or(0)
+        };
+
+        let mut content = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let mut x = 0;
+
+            for (offset, c) in line.char_indices() {
+                let cell = &line[offset..offset + c.len_utf8()];
+
+                if x == width {
+                    return Err(ParseError::at(input, cell, format!("row is longer than the first row ({} cells)", width)));
+                }
+
+                content.push(f(Point(x as i64, y as i64), c).map_err(|message| ParseError::at(input, cell, message))?);
+                x += 1;
+            }
+
+            if x < width {
+                match &ragged {
+                    RaggedRows::Reject => return Err(ParseError::at(input, &line[line.len()..], format!("row is shorter than the first row ({} cells)", width))),
+                    RaggedRows::Pad(value) => content.extend(std::iter::repeat_n(value.clone(), width - x))
+                }
+            }
+        }
+
+        Ok(Grid {
+            size: Point(width as i64, lines.len() as i64),
+            content: content.into_boxed_slice()
+        })
+    }
+}
+
+impl FrameWriter {
+    pub fn new(directory: impl AsRef<Path>, prefix: &str) -> io::Result<FrameWriter> {
+        fs::create_dir_all(&directory)?;
+
+        Ok(FrameWriter {
+            directory: directory.as_ref().to_path_buf(),
+            prefix: prefix.to_string(),
+            next_frame: 0
+        })
+    }
+
+    fn next_path(&mut self, extension: &str) -> PathBuf {
+        let path = self.directory.join(format!("{}{:05}.{}", self.prefix, self.next_frame, extension));
+        self.next_frame += 1;
+        path
+    }
+
+    pub fn write_ppm<T>(&mut self, grid: &Grid<T>, f: impl FnMut(&T) -> [u8; 3]) -> io::Result<PathBuf> {
+        let path = self.next_path("ppm");
+        grid.save_ppm(&path, f)?;
+        Ok(path)
+    }
+
+    pub fn write_pgm<T>(&mut self, grid: &Grid<T>, f: impl FnMut(&T) -> u8) -> io::Result<PathBuf> {
+        let path = self.next_path("pgm");
+        grid.save_pgm(&path, f)?;
+        Ok(path)
+    }
 }
 
 impl <T> DirectionMap<T> {
@@ -328,4 +569,136 @@ impl <T: Clone> DirectionMap<T> {
             west: value.clone()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, FrameWriter, Grid, Point, RaggedRows};
+
+    #[test]
+    fn set_and_index_update_in_place() {
+        let mut grid = Grid::new(Point(3, 2), 0);
+
+        assert_eq!(grid.set(Point(2, 1), 5), Some(0));
+        assert_eq!(grid.set(Point(3, 1), 5), None);
+
+        grid[Point(0, 0)] = 1;
+        *grid.get_mut(Point(1, 0)).unwrap() += 2;
+        grid.swap(Point(0, 0), Point(2, 1));
+
+        assert_eq!(grid.row(0), Some(&[5, 2, 0][..]));
+        assert_eq!(grid.row(1), Some(&[0, 0, 1][..]));
+        assert_eq!(grid[Point(2, 1)], 1);
+    }
+
+    #[test]
+    fn columns_and_rows_follow_layout() {
+        let mut grid = Grid::try_from("abc\ndef").unwrap();
+
+        for (position, value) in grid.iter_mut() {
+            if position.0 == 1 {
+                *value = value.to_ascii_uppercase();
+            }
+        }
+
+        for value in grid.column_mut(2).unwrap() {
+            *value = '#';
+        }
+
+        assert_eq!(grid.column(1).unwrap().collect::<String>(), "BE");
+        assert_eq!(grid.rows().map(|x| x.iter().collect::<String>()).collect::<Vec<String>>(), ["aB#", "dE#"]);
+        assert!(grid.column(3).is_none());
+
+        grid.fill('.');
+        assert!(grid.iter().all(|(_, x)| *x == '.'));
+    }
+
+    #[test]
+    fn parse_handles_line_endings_and_ragged_rows() {
+        let grid = Grid::try_from("ab\r\ncd\n").unwrap();
+        assert_eq!(grid.size, Point(2, 2));
+        assert_eq!(grid[Point(1, 1)], 'd');
+
+        let error = Grid::try_from("abc\nde\nfgh").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Grid::try_from("ab\ncde").err().unwrap();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "e"));
+
+        let padded = Grid::parse("abc\nd", RaggedRows::Pad('.'), |_, c| Ok(c)).unwrap();
+        assert_eq!(padded.row(1), Some(&['d', '.', '.'][..]));
+    }
+
+    #[test]
+    fn parse_maps_cells_and_reports_rejected_characters() {
+        let grid = Grid::parse("1.\n.2", RaggedRows::Reject, |_, c| c.to_digit(10).map(|x| x as i64).or((c == '.').then_some(-1)).ok_or("expected a digit")).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<&[i64]>>(), [[1, -1], [-1, 2]]);
+
+        let error = Grid::parse("12\n3x", RaggedRows::Reject, |_, c| c.to_digit(10).ok_or("expected a digit")).err().unwrap();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 2, "expected a digit"));
+    }
+
+    #[test]
+    fn combinators_accept_capturing_closures() {
+        let grid = Grid::try_from("ab\nba").unwrap();
+        let target = 'a';
+
+        assert_eq!(grid.count_where(|&x| x == target), 2);
+        assert_eq!(grid.find_all(|&x| x == target).map(|x| x.0).collect::<Vec<Point>>(), [Point(0, 0), Point(1, 1)]);
+
+        let mut seen = 0;
+        let numbered = grid.map(|_| {
+            seen += 1;
+            seen
+        });
+
+        let positions = grid.map_with_position(|p, _| p.0 * 10 + p.1);
+        let sums = numbered.zip_with(&positions, |a, b| a + b).unwrap();
+
+        assert_eq!(sums.rows().collect::<Vec<&[i64]>>(), [[1, 12], [4, 15]]);
+        assert_eq!(grid.filter_map(|p, &x| (x == 'b').then_some(p.0)).collect::<Vec<(Point, i64)>>(), [(Point(1, 0), 1), (Point(0, 1), 0)]);
+        assert!(grid.zip_with(&Grid::new(Point(1, 1), 0), |a, _| *a).is_none());
+    }
+
+    #[test]
+    fn render_and_overlays_draw_on_a_copy() {
+        let grid = Grid::parse("#..\n...\n..#", RaggedRows::Reject, |_, c| Ok(c == '#')).unwrap();
+        let mut rendered = grid.render(|&x| if x { '#' } else { '.' });
+
+        rendered
+            .draw_path([(Point(0, 1), Direction::East), (Point(1, 1), Direction::East), (Point(1, 1), Direction::North), (Point(1, 0), Direction::North)])
+            .draw_points([Point(2, 1), Point(5, 5)], 'O');
+
+        assert_eq!(rendered.to_string(), "#^.\n>+O\n..#");
+        assert_eq!(Grid::try_from("ab\ncd").unwrap().to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn images_are_written_as_binary_netpbm() {
+        let grid = Grid::try_from("#.\n.#").unwrap();
+        let mut ppm = vec![];
+        let mut pgm = vec![];
+
+        grid.write_ppm(&mut ppm, |&x| if x == '#' { [255, 0, 0] } else { [0, 0, 0] }).unwrap();
+        grid.scaled(2).write_pgm(&mut pgm, |&x| if x == '#' { 255 } else { 0 }).unwrap();
+
+        assert_eq!(ppm, [b"P6\n2 2\n255\n".as_slice(), &[255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0]].concat());
+        assert_eq!(pgm, [b"P5\n4 4\n255\n".as_slice(), &[255, 255, 0, 0, 255, 255, 0, 0, 0, 0, 255, 255, 0, 0, 255, 255]].concat());
+    }
+
+    #[test]
+    fn frame_writer_numbers_files() {
+        let directory = std::env::temp_dir().join(format!("aoc2024-frames-{}", std::process::id()));
+        let mut frames = FrameWriter::new(&directory, "frame").unwrap();
+        let grid = Grid::new(Point(2, 1), 0u8);
+
+        let first = frames.write_pgm(&grid, |&x| x).unwrap();
+        let second = frames.write_ppm(&grid, |&x| [x, x, x]).unwrap();
+
+        assert_eq!(first, directory.join("frame00000.pgm"));
+        assert_eq!(second, directory.join("frame00001.ppm"));
+        assert_eq!(std::fs::read(&first).unwrap(), b"P5\n2 1\n255\n\0\0");
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,189 @@
+use std::{fmt::Display, fs::OpenOptions, io::Write, path::Path, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use crate::params::Params;
+
+pub const DEFAULT_HISTORY_PATH: &str = "inputs/bench_history.tsv";
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub day: u32,
+    pub part: u32,
+    pub commit: String,
+    pub median: Duration,
+    pub input: String,
+    pub params: String
+}
+
+pub struct Regression {
+    pub best: HistoryEntry,
+    pub median: Duration,
+    pub percent: f64
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(String, std::io::Error),
+    Parse(usize, String)
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(path, error) => write!(f, "Could not access benchmark history {}: {}", path, error),
+            HistoryError::Parse(line, text) => write!(f, "Invalid benchmark history entry on line {}: {:?}", line, text)
+        }
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WARNING: Day {} Part {} regressed by {:.1}%: median {:.6}s vs best {:.6}s (commit {})",
+            self.best.day,
+            self.best.part,
+            self.percent,
+            self.median.as_secs_f64(),
+            self.best.median.as_secs_f64(),
+            self.best.commit
+        )
+    }
+}
+
+impl HistoryEntry {
+    pub fn new(day: u32, part: u32, input: &str, params: &Params, median: Duration) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|x| x.as_secs()).unwrap_or(0),
+            day,
+            part,
+            commit: option_env!("AOC_BUILD_COMMIT").unwrap_or("-").to_string(),
+            median,
+            input: input.to_string(),
+            params: Some(params.to_string()).filter(|x| !x.is_empty()).unwrap_or("-".into())
+        }
+    }
+
+    fn parse(line: &str) -> Option<HistoryEntry> {
+        let segments: Vec<&str> = line.split('\t').collect();
+
+        if segments.len() != 7 {
+            return None;
+        }
+
+        Some(HistoryEntry {
+            timestamp: segments[0].parse().ok()?,
+            day: segments[1].parse().ok()?,
+            part: segments[2].parse().ok()?,
+            commit: segments[3].to_string(),
+            median: Duration::try_from_secs_f64(segments[4].parse().ok()?).ok()?,
+            input: segments[5].to_string(),
+            params: segments[6].to_string()
+        })
+    }
+
+    fn same_run(&self, other: &HistoryEntry) -> bool {
+        self.day == other.day && self.part == other.part && self.input == other.input && self.params == other.params
+    }
+}
+
+impl Display for HistoryEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\t{}\t{}\t{}\t{:.9}\t{}\t{}", self.timestamp, self.day, self.part, self.commit, self.median.as_secs_f64(), self.input, self.params)
+    }
+}
+
+pub fn load(path: &str) -> Result<Vec<HistoryEntry>, HistoryError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(HistoryError::Io(path.to_string(), error))
+    };
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| HistoryEntry::parse(line).ok_or_else(|| HistoryError::Parse(index + 1, line.to_string())))
+        .collect()
+}
+
+pub fn append(path: &str, entry: &HistoryEntry) -> Result<(), HistoryError> {
+    let io_error = |x| HistoryError::Io(path.to_string(), x);
+
+    if let Some(parent) = Path::new(path).parent().filter(|x| !x.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+    writeln!(file, "{}", entry).map_err(io_error)
+}
+
+pub fn find_regression(history: &[HistoryEntry], entry: &HistoryEntry, threshold_percent: f64) -> Option<Regression> {
+    let best = history
+        .iter()
+        .filter(|x| x.same_run(entry))
+        .min_by_key(|x| x.median)
+        .filter(|x| !x.median.is_zero())?;
+
+    let percent = (entry.median.as_secs_f64() / best.median.as_secs_f64() - 1.0) * 100.0;
+
+    if percent > threshold_percent {
+        Some(Regression {
+            best: best.clone(),
+            median: entry.median,
+            percent
+        })
+    }
+    else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{find_regression, HistoryEntry};
+
+    fn entry(input: &str, params: &str, median: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: 0,
+            day: 14,
+            part: 1,
+            commit: "-".to_string(),
+            median: Duration::from_millis(median),
+            input: input.to_string(),
+            params: params.to_string()
+        }
+    }
+
+    #[test]
+    fn parse_round_trips() {
+        let original = entry("inputs/day14.txt", "width=11, height=7, steps=100", 25);
+        let parsed = HistoryEntry::parse(&original.to_string()).unwrap();
+
+        assert_eq!(parsed.to_string(), original.to_string());
+    }
+
+    #[test]
+    fn parse_rejects_short_rows() {
+        assert!(HistoryEntry::parse("0\t14\t1\tabc\t0.5").is_none());
+    }
+
+    #[test]
+    fn regression_only_compares_matching_runs() {
+        let history = vec![
+            entry("inputs/day14.txt", "width=11, height=7, steps=100", 1),
+            entry("inputs/alice/day14.txt", "width=101, height=103, steps=100", 2),
+            entry("inputs/day14.txt", "width=101, height=103, steps=100", 10)
+        ];
+
+        let current = entry("inputs/day14.txt", "width=101, height=103, steps=100", 10);
+        assert!(find_regression(&history, &current, 10.0).is_none());
+
+        let slower = entry("inputs/day14.txt", "width=101, height=103, steps=100", 20);
+        assert_eq!(find_regression(&history, &slower, 10.0).unwrap().best.median, Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,96 @@
+use std::{fmt::Display, io::Read};
+
+use crate::solver::SolverError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+    Text(String)
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<input-text>")
+        }
+    }
+}
+
+impl InputSource {
+    pub fn from_argument(argument: &str) -> InputSource {
+        if argument == "-" {
+            InputSource::Stdin
+        }
+        else {
+            InputSource::File(argument.to_string())
+        }
+    }
+
+    pub fn load(&self) -> Result<String, SolverError> {
+        match self {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                std::io::stdin().read_to_string(&mut content).map_err(|x| SolverError::Io(self.to_string(), x))?;
+                Ok(normalize(&content))
+            }
+            InputSource::Text(text) => Ok(normalize(text))
+        }
+    }
+
+    pub fn buffered(self) -> Result<InputSource, SolverError> {
+        match self {
+            InputSource::Stdin => self.load().map(InputSource::Text),
+            source => Ok(source)
+        }
+    }
+}
+
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut normalized = String::with_capacity(text.len());
+
+    for line in text.split('\n') {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+
+    let trimmed_length = normalized.trim_end().len();
+    normalized.truncate(trimmed_length);
+    normalized
+}
+
+pub fn read_file(path: &str) -> Result<String, SolverError> {
+    let content = std::fs::read_to_string(path).map_err(|x| SolverError::Io(path.to_string(), x))?;
+    Ok(normalize(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn normalize_strips_byte_order_mark() {
+        assert_eq!(normalize("\u{feff}1 2\n3 4"), "1 2\n3 4");
+        assert_eq!(normalize("1 \u{feff}2"), "1 \u{feff}2");
+    }
+
+    #[test]
+    fn normalize_converts_crlf() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4");
+    }
+
+    #[test]
+    fn normalize_trims_trailing_whitespace_per_line() {
+        assert_eq!(normalize("  a \t\nb  \n  c"), "  a\nb\n  c");
+    }
+
+    #[test]
+    fn normalize_drops_trailing_blank_lines() {
+        assert_eq!(normalize("a\n\nb\n\n\n  \n"), "a\n\nb");
+        assert_eq!(normalize("\n\n"), "");
+    }
+}
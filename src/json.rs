@@ -0,0 +1,242 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{memory::AllocationStats, runner::{Failure, PhaseTimings, RunResult, Skipped}, solver::{Answer, SolverError}};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Integer(value) => write!(f, "{}", value),
+            Json::Number(value) if value.is_finite() => write!(f, "{}", value),
+            Json::Number(_) => write!(f, "null"),
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                write!(f, "[")?;
+
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", value)?;
+                }
+
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, value: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+
+    write!(f, "\"")
+}
+
+impl Json {
+    pub fn object(fields: Vec<(&str, Json)>) -> Json {
+        Json::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Json::Integer(value.into())
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Json::Integer(value as i64)
+    }
+}
+
+impl From<Duration> for Json {
+    fn from(value: Duration) -> Self {
+        Json::Number(value.as_secs_f64())
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(value: &Answer) -> Self {
+        match value {
+            Answer::Integer(value) => Json::Integer(*value),
+            Answer::Text(value) => Json::String(value.clone()),
+            Answer::Lines(lines) => Json::Array(lines.iter().map(|x| x.as_str().into()).collect())
+        }
+    }
+}
+
+impl From<&PhaseTimings> for Json {
+    fn from(value: &PhaseTimings) -> Self {
+        Json::object(vec![
+            ("read", value.read.into()),
+            ("parse", value.parse.into()),
+            ("solve", value.solve.into()),
+            ("total", value.total().into())
+        ])
+    }
+}
+
+impl From<&AllocationStats> for Json {
+    fn from(value: &AllocationStats) -> Self {
+        Json::object(vec![
+            ("allocations", value.allocations.into()),
+            ("bytes", value.bytes.into()),
+            ("peak", value.peak.into())
+        ])
+    }
+}
+
+impl From<&SolverError> for Json {
+    fn from(value: &SolverError) -> Self {
+        let kind = match value {
+            SolverError::UnknownDay(_) => "unknown_day",
+            SolverError::UnknownPart(_) => "unknown_part",
+            SolverError::UnknownParameter(_) => "unknown_parameter",
+            SolverError::InvalidParameter(_, _) => "invalid_parameter",
+            SolverError::Timeout(_) => "timeout",
+            SolverError::Panicked(_) => "panicked",
+            SolverError::Io(_, _) => "io",
+            SolverError::Parse(_) => "parse"
+        };
+
+        let mut fields = vec![("kind", kind.into())];
+
+        match value {
+            SolverError::Parse(error) => {
+                fields.push(("message", error.message.as_str().into()));
+                fields.push(("file", error.file.as_deref().map_or(Json::Null, |x| x.into())));
+                fields.push(("line", error.line.into()));
+                fields.push(("column", error.column.into()));
+                fields.push(("text", error.text.as_str().into()));
+            }
+            error => {
+                fields.push(("message", error.to_string().into()));
+            }
+        }
+
+        Json::object(fields)
+    }
+}
+
+pub fn run_result(result: &RunResult) -> Json {
+    Json::object(vec![
+        ("day", result.day.into()),
+        ("part", result.part.into()),
+        ("input", result.input.as_str().into()),
+        ("params", Json::Object(result.params.iter().map(|(name, value)| (name.to_string(), Json::Integer(value))).collect())),
+        ("answer", (&result.answer).into()),
+        ("timings", (&result.timings).into()),
+        ("memory", result.memory.as_ref().map_or(Json::Null, |x| x.into()))
+    ])
+}
+
+pub fn run_error(day: u32, part: Option<u32>, input: &str, error: &SolverError) -> Json {
+    Json::object(vec![
+        ("day", day.into()),
+        ("part", part.map_or(Json::Null, |x| x.into())),
+        ("input", input.into()),
+        ("error", error.into())
+    ])
+}
+
+pub fn failure(failure: &Failure) -> Json {
+    run_error(failure.day, Some(failure.part), &failure.input, &failure.error)
+}
+
+pub fn skipped(skipped: &Skipped) -> Json {
+    run_error(skipped.day, None, &skipped.input, &skipped.error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Json;
+    use crate::{parse::ParseError, solver::SolverError};
+
+    #[test]
+    fn strings_escape_quotes_backslashes_and_control_characters() {
+        let json = Json::from("say \"hi\"\\path\n\r\t\u{1}é");
+
+        assert_eq!(json.to_string(), r#""say \"hi\"\\path\n\r\t\u0001é""#);
+    }
+
+    #[test]
+    fn non_finite_numbers_become_null() {
+        let values = Json::Array(vec![Json::Number(1.5), Json::Number(f64::NAN), Json::Number(f64::INFINITY), Json::Number(f64::NEG_INFINITY)]);
+
+        assert_eq!(values.to_string(), "[1.5,null,null,null]");
+    }
+
+    #[test]
+    fn objects_escape_keys() {
+        let json = Json::object(vec![("a\"b", Json::Null), ("c", Json::Bool(true)), ("d", Json::Integer(-3))]);
+
+        assert_eq!(json.to_string(), r#"{"a\"b":null,"c":true,"d":-3}"#);
+    }
+
+    #[test]
+    fn parse_errors_include_location() {
+        let error = SolverError::Parse(ParseError::at_offset("1 2\n3 \"x", 6, 2, "expected a number").with_file("in.txt"));
+
+        assert_eq!(
+            Json::from(&error).to_string(),
+            r#"{"kind":"parse","message":"expected a number","file":"in.txt","line":2,"column":3,"text":"\"x"}"#
+        );
+    }
+
+    #[test]
+    fn other_errors_use_display_message() {
+        assert_eq!(Json::from(&SolverError::UnknownPart(3)).to_string(), r#"{"kind":"unknown_part","message":"Unknown part 3, expected 1 or 2"}"#);
+    }
+}
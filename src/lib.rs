@@ -0,0 +1,22 @@
+pub mod geometry;
+pub mod algebra;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod history;
+pub mod input;
+pub mod json;
+pub mod memory;
+pub mod params;
+pub mod parse;
+pub mod pool;
+pub mod random;
+pub mod solver;
+pub mod runner;
+pub mod scaffold;
+pub mod watch;
+pub mod days;
+
+pub use input::InputSource;
+pub use parse::ParseError;
+pub use solver::{Answer, Solution, Solver, SolverError};
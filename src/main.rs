@@ -1,50 +1,303 @@
-use std::time::Instant;
-
-mod geometry;
-mod algebra;
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
+use std::{fmt::Display, path::Path, str::FromStr, time::Duration};
+
+use aoc2024::{answers, bench, cancel, history, json::{self, Json}, memory, params::Params, pool, random::Random, runner, scaffold, solver, watch, InputSource, SolverError};
+use cli::{ArgumentError, Arguments};
+
+mod cli;
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(())
+        }
+    }
+}
+
+const FLAGS: &[&str] = &["watch", "sequential", "memory", "all-profiles"];
+const OPTIONS: &[&str] = &["format", "history", "input-text", "iterations", "output", "param", "profile", "seed", "size", "threads", "threshold", "timeout", "warmup"];
 
 fn main() {
-    let day: i32 = std::env::args().nth(1).unwrap_or("1".into()).parse().expect("Invalid day");
-    let part: i32 = std::env::args().nth(2).unwrap_or("1".into()).parse().expect("Invalid part");
-    let default_input_path = format!("inputs/day{}.txt", day);
-    let input_path: String = std::env::args().nth(3).unwrap_or(default_input_path);
-
-    println!("Running Day {} Part {} on {}", day, part, input_path);
-
-    let now = Instant::now();
-    match day {
-        1 => day1::run(input_path.as_str(), part),
-        2 => day2::run(input_path.as_str(), part),
-        3 => day3::run(input_path.as_str(), part),
-        4 => day4::run(input_path.as_str(), part),
-        5 => day5::run(input_path.as_str(), part),
-        6 => day6::run(input_path.as_str(), part),
-        7 => day7::run(input_path.as_str(), part),
-        8 => day8::run(input_path.as_str(), part),
-        9 => day9::run(input_path.as_str(), part),
-        10 => day10::run(input_path.as_str(), part),
-        11 => day11::run(input_path.as_str(), part),
-        12 => day12::run(input_path.as_str(), part),
-        13 => day13::run(input_path.as_str(), part),
-        14 => day14::run(input_path.as_str(), part),
-        _ => panic!("Unknown day specified")
+    let arguments = or_exit(Arguments::parse(std::env::args().skip(1), FLAGS, OPTIONS));
+    let command = arguments.positional(0).unwrap_or("1");
+
+    if arguments.option("memory").is_some() {
+        memory::enable();
+    }
+
+    match command {
+        "list" => {
+            for solver in solver::all() {
+                let params = Params::defaults(solver.parameters);
+
+                if params.is_empty() {
+                    println!("Day {}", solver.day);
+                }
+                else {
+                    println!("Day {} ({})", solver.day, params);
+                }
+            }
+        }
+        "run-all" => run_all(&arguments),
+        "verify" => verify(&arguments),
+        "bench" => run_bench(&arguments),
+        "new" => new_day(&arguments),
+        "generate" => generate(&arguments),
+        _ => run_single(&arguments)
+    }
+}
+
+fn or_exit<T, E: Display>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn threads(arguments: &Arguments) -> usize {
+    if arguments.option("sequential").is_some() || memory::is_enabled() {
+        1
+    }
+    else {
+        or_exit(arguments.option_or("threads", pool::default_threads()))
+    }
+}
+
+fn timeout(arguments: &Arguments) -> Option<Duration> {
+    let value = arguments.option("timeout")?;
+    let timeout = value.parse().ok().and_then(|x| Duration::try_from_secs_f64(x).ok());
+
+    Some(or_exit(timeout.ok_or_else(|| ArgumentError::InvalidValue("--timeout".to_string(), value.to_string()))))
+}
+
+fn input_source(arguments: &Arguments, index: usize, day: u32) -> InputSource {
+    if let Some(text) = arguments.option("input-text") {
+        InputSource::Text(text.to_string())
+    }
+    else {
+        InputSource::from_argument(arguments.positional(index).unwrap_or(&runner::input_path(day, arguments.option("profile"))))
+    }
+}
+
+fn run_all(arguments: &Arguments) {
+    let format: Format = or_exit(arguments.option_or("format", Format::Text));
+    let summary = runner::run_all(threads(arguments), timeout(arguments), arguments.option("profile"));
+
+    match format {
+        Format::Text => runner::print_table(&summary),
+        Format::Json => {
+            let total: Duration = summary.results.iter().map(|x| x.timings.total()).sum();
+
+            println!("{}", Json::object(vec![
+                ("results", Json::Array(summary.results.iter().map(json::run_result).collect())),
+                ("failures", Json::Array(summary.failures.iter().map(json::failure).collect())),
+                ("skipped", Json::Array(summary.skipped.iter().map(json::skipped).collect())),
+                ("still_running", cancel::still_running().into()),
+                ("total", total.into())
+            ]));
+        }
+    }
+}
+
+fn new_day(arguments: &Arguments) {
+    let Some(day) = arguments.positional(1) else {
+        eprintln!("Usage: new <day>");
+        std::process::exit(1);
+    };
+
+    let day: u32 = or_exit(day.parse().map_err(|_| format!("Invalid value {:?} for day", day)));
+
+    for path in or_exit(scaffold::create(day)) {
+        println!("Created {}", path);
+    }
+
+    println!("Registered day{} in {}", day, scaffold::REGISTRY_PATH);
+}
+
+fn generate(arguments: &Arguments) {
+    let day: u32 = or_exit(arguments.positional_or(1, "day", 1));
+    let size: usize = or_exit(arguments.option_or("size", 1000));
+    let seed: Option<u64> = or_exit(arguments.option("seed").map(|x| x.parse().map_err(|_| ArgumentError::InvalidValue("--seed".to_string(), x.to_string()))).transpose());
+
+    let solver = or_exit(solver::find(day));
+    let params = or_exit(solver.params(arguments.options("param")));
+    let mut random = seed.map_or_else(Random::from_time, Random::new);
+
+    let Some(input) = solver.generate(&mut random, size, &params) else {
+        eprintln!("No input generator for day {}", day);
+        std::process::exit(1);
+    };
+
+    match arguments.option("output") {
+        Some(path) => or_exit(std::fs::write(path, input + "\n").map_err(|x| format!("Could not write {}: {}", path, x))),
+        None => println!("{}", input)
+    }
+}
+
+fn verify(arguments: &Arguments) {
+    let answers_path = arguments.positional(1).map_or_else(|| answers::answers_path(arguments.option("profile")), |x| x.to_string());
+    let book = or_exit(answers::AnswerBook::load(&answers_path));
+    let summary = runner::run_all(threads(arguments), timeout(arguments), arguments.option("profile"));
+
+    if !runner::print_verification(&summary, &book) {
+        std::process::exit(1);
+    }
+}
+
+fn run_bench(arguments: &Arguments) {
+    let day: u32 = or_exit(arguments.positional_or(1, "day", 1));
+    let part: u32 = or_exit(arguments.positional_or(2, "part", 1));
+    let source = input_source(arguments, 3, day);
+    let warmup: usize = or_exit(arguments.option_or("warmup", 3));
+    let iterations: usize = or_exit(arguments.option_or("iterations", 20));
+
+    let solver = or_exit(solver::find(day));
+    let params = or_exit(solver.params(arguments.options("param")));
+    let result = or_exit(bench::bench(solver, part, source.clone(), &params, warmup, iterations));
+
+    bench::print(&result);
+
+    let history_path = arguments.option("history").unwrap_or(history::DEFAULT_HISTORY_PATH);
+    let threshold: f64 = or_exit(arguments.option_or("threshold", history::DEFAULT_REGRESSION_THRESHOLD));
+    let InputSource::File(input) = &source else {
+        println!("Not recording benchmark history for {}", source);
+        return;
     };
 
-    let after = now.elapsed();
+    let past = or_exit(history::load(history_path));
+    let entry = history::HistoryEntry::new(day, part, input, &params, result.total.median);
+
+    if let Some(regression) = history::find_regression(&past, &entry, threshold) {
+        println!("{}", regression);
+    }
+
+    or_exit(history::append(history_path, &entry));
+}
+
+fn run_single(arguments: &Arguments) {
+    let day: u32 = or_exit(arguments.positional_or(0, "day", 1));
+    let part: u32 = or_exit(arguments.positional_or(1, "part", 1));
+    let source = input_source(arguments, 2, day);
+    let format: Format = or_exit(arguments.option_or("format", Format::Text));
+
+    if arguments.option("all-profiles").is_some() {
+        run_profiles(arguments, day, part, format);
+        return;
+    }
+
+    if arguments.option("watch").is_none() {
+        if !run_once(arguments, day, part, &source, format) {
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
+    let InputSource::File(path) = &source else {
+        eprintln!("--watch requires an input file");
+        std::process::exit(1);
+    };
+
+    watch::watch(Path::new(path), watch::POLL_INTERVAL, || {
+        print!("{}", watch::CLEAR_SCREEN);
+        run_once(arguments, day, part, &source, format);
+
+        if format == Format::Text {
+            println!("\nWatching {} for changes, press Ctrl+C to stop", path);
+        }
+    });
+}
+
+fn run_profiles(arguments: &Arguments, day: u32, part: u32, format: Format) {
+    let solver = or_exit(solver::find(day).and_then(|x| x.check_part(part).map(|_| x)));
+    let params = or_exit(solver.params(arguments.options("param")));
+    let results = or_exit(runner::run_profiles(solver, part, &params, threads(arguments), timeout(arguments)));
+
+    match format {
+        Format::Text => {
+            println!("Running Day {} Part {} on {} profiles", day, part, results.len());
+            runner::print_profiles(&results);
+        }
+        Format::Json => {
+            let results = results.iter().map(|x| match &x.result {
+                Ok(result) => json::run_result(result),
+                Err(error) => json::run_error(day, Some(part), &runner::input_path(day, Some(&x.profile)), error)
+            });
+
+            println!("{}", Json::Array(results.collect()));
+        }
+    }
+}
+
+fn run_once(arguments: &Arguments, day: u32, part: u32, source: &InputSource, format: Format) -> bool {
+    let timeout = timeout(arguments);
+    let result = solver::find(day).and_then(|x| x.check_part(part).map(|_| x)).and_then(|solver| {
+        let params = solver.params(arguments.options("param"))?;
+
+        if format == Format::Text {
+            println!("Running Day {} Part {} on {}", day, part, source);
+
+            if !arguments.options("param").is_empty() {
+                println!("Parameters: {}", params);
+            }
+        }
+
+        runner::run(solver, part, source, &params, timeout)
+    });
+
+    if format == Format::Json {
+        return print_json_result(day, part, source, result);
+    }
+
+    let result = match result {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("{}", error);
+            return false;
+        }
+    };
+
+    if result.answer.is_multiline() {
+        println!("Answer:\n{}", result.answer);
+    }
+    else {
+        println!("{}", result.answer);
+    }
+
+    println!("Runtime: {}", result.timings.total().as_secs_f64());
+    println!("  read {:.6}s, parse {:.6}s, solve {:.6}s", result.timings.read.as_secs_f64(), result.timings.parse.as_secs_f64(), result.timings.solve.as_secs_f64());
+
+    if let Some(memory) = result.memory {
+        println!("Memory: {}", memory);
+    }
+
+    true
+}
 
-    println!("Runtime: {}", after.as_secs_f64());
-}
\ No newline at end of file
+fn print_json_result(day: u32, part: u32, source: &InputSource, result: Result<runner::RunResult, SolverError>) -> bool {
+    match result {
+        Ok(result) => {
+            println!("{}", json::run_result(&result));
+            true
+        }
+        Err(error) => {
+            println!("{}", json::run_error(day, Some(part), &source.to_string(), &error));
+            false
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, fmt::Display, sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering}};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+pub struct CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocationStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    pub peak: usize
+}
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED.fetch_add(size, Ordering::Relaxed);
+            let live = LIVE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(live, Ordering::Relaxed);
+        }
+    }
+
+    fn record_deallocation(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+
+        if !pointer.is_null() {
+            CountingAllocator::record_allocation(layout.size());
+        }
+
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+
+        if !pointer.is_null() {
+            CountingAllocator::record_allocation(layout.size());
+        }
+
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        CountingAllocator::record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+
+        if !new_pointer.is_null() {
+            CountingAllocator::record_deallocation(layout.size());
+            CountingAllocator::record_allocation(new_size);
+        }
+
+        new_pointer
+    }
+}
+
+impl Display for AllocationStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocations, {} bytes allocated, {} bytes peak", self.allocations, self.bytes, self.peak)
+    }
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as usize
+    };
+
+    (result, Some(stats))
+}
@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use crate::solver::SolverError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub default: i64,
+    pub minimum: i64,
+    pub maximum: i64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>
+}
+
+impl Parameter {
+    pub const fn new(name: &'static str, default: i64, minimum: i64) -> Parameter {
+        Parameter::bounded(name, default, minimum, i64::MAX)
+    }
+
+    pub const fn bounded(name: &'static str, default: i64, minimum: i64, maximum: i64) -> Parameter {
+        Parameter {
+            name,
+            default,
+            minimum,
+            maximum
+        }
+    }
+}
+
+impl Display for Parameter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.default)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.values.iter().map(|(name, value)| format!("{}={}", name, value)).collect();
+        write!(f, "{}", values.join(", "))
+    }
+}
+
+impl Params {
+    pub fn defaults(declared: &[Parameter]) -> Params {
+        Params {
+            values: declared.iter().map(|x| (x.name, x.default)).collect()
+        }
+    }
+
+    pub fn resolve(declared: &[Parameter], overrides: &[String]) -> Result<Params, SolverError> {
+        let mut params = Params::defaults(declared);
+
+        for assignment in overrides {
+            let (name, value) = assignment.split_once('=').unwrap_or((assignment, ""));
+            let parameter = declared.iter().find(|x| x.name == name).ok_or_else(|| SolverError::UnknownParameter(name.to_string()))?;
+            let value: i64 = value.parse().map_err(|_| SolverError::InvalidParameter(name.to_string(), value.to_string()))?;
+
+            if value < parameter.minimum || value > parameter.maximum {
+                return Err(SolverError::InvalidParameter(name.to_string(), value.to_string()));
+            }
+
+            params.set(parameter.name, value);
+        }
+
+        Ok(params)
+    }
+
+    fn set(&mut self, name: &'static str, value: i64) {
+        if let Some(entry) = self.values.iter_mut().find(|(x, _)| *x == name) {
+            entry.1 = value;
+        }
+    }
+
+    pub fn get(&self, name: &str) -> i64 {
+        self.values.iter().find(|(x, _)| *x == name).map(|(_, value)| *value).unwrap_or_else(|| panic!("parameter {} is not declared", name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, i64)> + '_ {
+        self.values.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parameter, Params};
+
+    const DECLARED: &[Parameter] = &[
+        Parameter::new("size", 10, 1),
+        Parameter::bounded("steps", 5, 0, 20)
+    ];
+
+    fn resolve(overrides: &[&str]) -> Option<Params> {
+        let overrides: Vec<String> = overrides.iter().map(|x| x.to_string()).collect();
+        Params::resolve(DECLARED, &overrides).ok()
+    }
+
+    #[test]
+    fn resolve_applies_overrides() {
+        let params = resolve(&["steps=20", "size=3"]).unwrap();
+
+        assert_eq!(params.get("size"), 3);
+        assert_eq!(params.get("steps"), 20);
+    }
+
+    #[test]
+    fn resolve_rejects_values_out_of_bounds() {
+        assert!(resolve(&["size=0"]).is_none());
+        assert!(resolve(&["steps=21"]).is_none());
+        assert!(resolve(&["steps=-1"]).is_none());
+        assert!(resolve(&["steps=x"]).is_none());
+        assert!(resolve(&["depth=1"]).is_none());
+    }
+}
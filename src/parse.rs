@@ -0,0 +1,106 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub source_line: String,
+    pub message: String
+}
+
+impl ParseError {
+    pub fn at_offset(input: &str, offset: usize, length: usize, message: impl Into<String>) -> ParseError {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let line_end = input[offset..].find('\n').map(|x| x + offset).unwrap_or(input.len());
+        let text_end = (offset + length).min(input.len());
+
+        ParseError {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input.get(offset..text_end).unwrap_or("").to_string(),
+            source_line: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            message: message.into()
+        }
+    }
+
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).saturating_sub(start);
+        ParseError::at_offset(input, offset, fragment.len(), message)
+    }
+
+    pub fn at_end(input: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, input.len(), 0, message)
+    }
+
+    pub fn with_file(self, file: &str) -> ParseError {
+        ParseError {
+            file: Some(file.to_string()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}: {}", self.file.as_deref().unwrap_or("<input>"), self.line, self.column, self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, ", found {:?}", self.text)?;
+        }
+
+        let caret_offset: String = self.source_line.chars().take(self.column - 1).map(|x| if x == '\t' { '\t' } else { ' ' }).collect();
+        let caret_length = self.text.chars().count().max(1);
+
+        write!(f, "\n    {}\n    {}{}", self.source_line, caret_offset, "^".repeat(caret_length))
+    }
+}
+
+pub fn parse_number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(input, token, "expected a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_number, ParseError};
+
+    #[test]
+    fn at_offset_locates_line_and_column() {
+        let error = ParseError::at_offset("12\n34 x5\n67", 6, 1, "bad");
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "x");
+        assert_eq!(error.source_line, "34 x5");
+    }
+
+    #[test]
+    fn at_offset_counts_columns_in_characters() {
+        let input = "é€\nää?";
+        let error = ParseError::at_offset(input, input.find('?').unwrap(), 1, "bad");
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.to_string(), "<input>:2:3: bad, found \"?\"\n    ää?\n      ^");
+    }
+
+    #[test]
+    fn at_offset_clamps_past_the_end() {
+        let error = ParseError::at_offset("ab\ncd", 100, 5, "bad");
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "");
+    }
+
+    #[test]
+    fn at_locates_fragments() {
+        let input = "1 2\n3 x";
+        let token = input.split_whitespace().last().unwrap();
+        let error = parse_number::<u32>(input, token).unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+        assert_eq!(error.message, "expected a number");
+    }
+}
@@ -0,0 +1,60 @@
+use std::{num::NonZeroUsize, sync::{atomic::{AtomicUsize, Ordering}, Mutex}, thread};
+
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+pub fn map<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let threads = threads.clamp(1, items.len().max(1));
+
+    if threads == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter().map(|x| x.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::map;
+
+    #[test]
+    fn map_keeps_input_order_across_threads() {
+        let items: Vec<u64> = (0..32).collect();
+
+        let results = map(&items, 4, |&x| {
+            thread::sleep(Duration::from_millis((32 - x) % 7));
+            x * 10
+        });
+
+        assert_eq!(results, items.iter().map(|x| x * 10).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn map_handles_more_threads_than_items() {
+        assert_eq!(map(&[1, 2], 8, |x| x + 1), vec![2, 3]);
+        assert!(map(&[] as &[u32], 8, |x| x + 1).is_empty());
+    }
+}
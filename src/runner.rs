@@ -0,0 +1,459 @@
+use std::{fs, io::ErrorKind, path::Path, sync::mpsc::{self, RecvTimeoutError}, thread, time::{Duration, Instant}};
+
+use crate::{answers::{AnswerBook, Verdict}, cancel::{self, CancelToken}, input::InputSource, memory::{self, AllocationStats}, params::Params, pool, solver::{self, Answer, Solved, Solver, SolverError}};
+
+pub const INPUTS_DIRECTORY: &str = "inputs";
+pub const CANCEL_GRACE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhaseTimings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration
+}
+
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub params: Params,
+    pub answer: Answer,
+    pub timings: PhaseTimings,
+    pub memory: Option<AllocationStats>
+}
+
+pub struct RunSummary {
+    pub results: Vec<RunResult>,
+    pub failures: Vec<Failure>,
+    pub skipped: Vec<Skipped>
+}
+
+struct TableRow<'a> {
+    day: u32,
+    part: u32,
+    text: String,
+    time: Duration,
+    outcome: Result<&'a Answer, &'a SolverError>,
+    memory: Option<AllocationStats>
+}
+
+pub struct ProfileResult {
+    pub profile: String,
+    pub result: Result<RunResult, SolverError>
+}
+
+pub struct Failure {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub error: SolverError
+}
+
+pub struct Skipped {
+    pub day: u32,
+    pub input: String,
+    pub error: SolverError
+}
+
+impl PhaseTimings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+}
+
+pub fn default_input_path(day: u32) -> String {
+    format!("{}/day{}.txt", INPUTS_DIRECTORY, day)
+}
+
+pub fn input_path(day: u32, profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("{}/{}/day{}.txt", INPUTS_DIRECTORY, profile, day),
+        None => default_input_path(day)
+    }
+}
+
+pub fn profiles() -> Result<Vec<String>, SolverError> {
+    let entries = fs::read_dir(INPUTS_DIRECTORY).map_err(|x| SolverError::Io(INPUTS_DIRECTORY.to_string(), x))?;
+    let mut profiles = vec![];
+
+    for entry in entries {
+        let entry = entry.map_err(|x| SolverError::Io(INPUTS_DIRECTORY.to_string(), x))?;
+
+        if entry.path().is_dir() {
+            profiles.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+
+    profiles.sort();
+    Ok(profiles)
+}
+
+pub fn read_input(source: &InputSource) -> Result<(String, Duration), SolverError> {
+    let now = Instant::now();
+    let input = source.load()?;
+    Ok((input, now.elapsed()))
+}
+
+pub fn run(solver: &Solver, part: u32, source: &InputSource, params: &Params, timeout: Option<Duration>) -> Result<RunResult, SolverError> {
+    solver.check_part(part)?;
+
+    let (input, read) = read_input(source)?;
+
+    let (solved, memory) = memory::measure(|| match timeout {
+        Some(timeout) => solve_with_timeout(*solver, input, part, params.clone(), timeout),
+        None => solver.solve(&input, part, params)
+    });
+
+    let solved = solved.map_err(|x| match x {
+        SolverError::Parse(error) => SolverError::Parse(error.with_file(&source.to_string())),
+        error => error
+    })?;
+
+    Ok(RunResult {
+        day: solver.day,
+        part,
+        input: source.to_string(),
+        params: params.clone(),
+        answer: solved.answer,
+        timings: PhaseTimings {
+            read,
+            parse: solved.parse_time,
+            solve: solved.solve_time
+        },
+        memory
+    })
+}
+
+fn solve_with_timeout(solver: Solver, input: String, part: u32, params: Params, timeout: Duration) -> Result<Solved, SolverError> {
+    let token = CancelToken::new();
+    let solver_token = token.clone();
+    let (sender, receiver) = mpsc::channel();
+
+    let handle = thread::spawn(move || {
+        solver_token.install();
+        let _ = sender.send(solver.solve(&input, part, &params));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(solved) => {
+            let _ = handle.join();
+            return solved;
+        }
+        Err(RecvTimeoutError::Disconnected) => return Err(SolverError::Panicked(panic_message(handle.join()))),
+        Err(RecvTimeoutError::Timeout) => {}
+    }
+
+    token.cancel();
+
+    match receiver.recv_timeout(CANCEL_GRACE) {
+        Err(RecvTimeoutError::Timeout) => cancel::abandon(handle),
+        _ => {
+            let _ = handle.join();
+        }
+    }
+
+    Err(SolverError::Timeout(timeout))
+}
+
+fn panic_message(result: thread::Result<()>) -> String {
+    let Err(payload) = result else {
+        return "solver exited without an answer".to_string();
+    };
+
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    }
+    else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    }
+    else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn run_all(threads: usize, timeout: Option<Duration>, profile: Option<&str>) -> RunSummary {
+    let solvers: Vec<&Solver> = solver::all().collect();
+    let mut summary = RunSummary {
+        results: vec![],
+        failures: vec![],
+        skipped: vec![]
+    };
+
+    for day_summary in pool::map(&solvers, threads, |solver| run_day(solver, timeout, profile)) {
+        summary.results.extend(day_summary.results);
+        summary.failures.extend(day_summary.failures);
+        summary.skipped.extend(day_summary.skipped);
+    }
+
+    summary
+}
+
+fn run_day(solver: &Solver, timeout: Option<Duration>, profile: Option<&str>) -> RunSummary {
+    let input_path = input_path(solver.day, profile);
+    let mut summary = RunSummary {
+        results: vec![],
+        failures: vec![],
+        skipped: vec![]
+    };
+
+    if !Path::new(&input_path).exists() {
+        let error = SolverError::Io(input_path.clone(), ErrorKind::NotFound.into());
+        summary.skipped.push(Skipped { day: solver.day, input: input_path, error });
+        return summary;
+    }
+
+    for part in 1..=2 {
+        match run(solver, part, &InputSource::File(input_path.clone()), &Params::defaults(solver.parameters), timeout) {
+            Ok(result) => summary.results.push(result),
+            Err(error) => summary.failures.push(Failure { day: solver.day, part, input: input_path.clone(), error })
+        }
+    }
+
+    summary
+}
+
+fn table_rows(summary: &RunSummary) -> Vec<TableRow<'_>> {
+    let results = summary.results.iter().map(|x| TableRow {
+        day: x.day,
+        part: x.part,
+        text: table_answer(&x.answer),
+        time: x.timings.total(),
+        outcome: Ok(&x.answer),
+        memory: x.memory
+    });
+
+    let failures = summary.failures.iter().map(|x| TableRow {
+        day: x.day,
+        part: x.part,
+        text: failure_text(&x.error).to_string(),
+        time: match x.error {
+            SolverError::Timeout(timeout) => timeout,
+            _ => Duration::ZERO
+        },
+        outcome: Err(&x.error),
+        memory: None
+    });
+
+    let mut rows: Vec<TableRow> = results.chain(failures).collect();
+    rows.sort_by_key(|x| (x.day, x.part));
+    rows
+}
+
+fn failure_text(error: &SolverError) -> &'static str {
+    match error {
+        SolverError::Timeout(_) => "TIMEOUT",
+        _ => "ERROR"
+    }
+}
+
+fn failure_reason(error: &SolverError) -> String {
+    match error {
+        SolverError::Timeout(_) => "timed out".to_string(),
+        SolverError::Parse(_) => "parse error".to_string(),
+        SolverError::Io(_, _) => "could not read input".to_string(),
+        error => error.to_string()
+    }
+}
+
+fn print_skipped(summary: &RunSummary) {
+    for failure in summary.failures.iter() {
+        if !matches!(failure.error, SolverError::Timeout(_)) {
+            println!("Failed Day {} part {}: {}", failure.day, failure.part, failure.error);
+        }
+    }
+
+    for skipped in summary.skipped.iter() {
+        println!("Skipped Day {}: {}", skipped.day, skipped.error);
+    }
+
+    print_still_running();
+}
+
+pub fn print_still_running() {
+    let running = cancel::still_running();
+
+    if running > 0 {
+        println!("Warning: {} timed out solver thread(s) still running, later timings may be inflated", running);
+
+        if memory::is_enabled() {
+            println!("Warning: allocation counts are process-wide and may include allocations from those threads");
+        }
+    }
+}
+
+pub fn print_table(summary: &RunSummary) {
+    let rows = table_rows(summary);
+    let answer_width = rows.iter().map(|x| x.text.len()).chain([6]).max().unwrap();
+
+    let show_memory = rows.iter().any(|x| x.memory.is_some());
+
+    print!("{:>3}  {:>4}  {:<answer_width$}  {:>12}", "Day", "Part", "Answer", "Time (s)");
+
+    if show_memory {
+        print!("  {:>10}  {:>14}  {:>14}", "Allocs", "Bytes", "Peak bytes");
+    }
+
+    println!();
+
+    for row in rows.iter() {
+        print!("{:>3}  {:>4}  {:<answer_width$}  {:>12.6}", row.day, row.part, row.text, row.time.as_secs_f64());
+
+        if let Some(memory) = row.memory {
+            print!("  {:>10}  {:>14}  {:>14}", memory.allocations, memory.bytes, memory.peak);
+        }
+
+        println!();
+    }
+
+    let total: Duration = rows.iter().map(|x| x.time).sum();
+    println!("{:<9}  {:<answer_width$}  {:>12.6}", "Total", "", total.as_secs_f64());
+
+    print_skipped(summary);
+}
+
+pub fn run_profiles(solver: &Solver, part: u32, params: &Params, threads: usize, timeout: Option<Duration>) -> Result<Vec<ProfileResult>, SolverError> {
+    let profiles = profiles()?;
+    let results = pool::map(&profiles, threads, |profile| run(solver, part, &InputSource::File(input_path(solver.day, Some(profile))), params, timeout));
+
+    Ok(profiles.into_iter().zip(results).map(|(profile, result)| ProfileResult { profile, result }).collect())
+}
+
+pub fn print_profiles(results: &[ProfileResult]) {
+    let texts: Vec<String> = results.iter().map(|x| match &x.result {
+        Ok(result) => table_answer(&result.answer),
+        Err(SolverError::Timeout(_)) => "TIMEOUT".to_string(),
+        Err(_) => "ERROR".to_string()
+    }).collect();
+
+    let profile_width = results.iter().map(|x| x.profile.len()).chain([7]).max().unwrap();
+    let answer_width = texts.iter().map(|x| x.len()).chain([6]).max().unwrap();
+
+    println!("{:<profile_width$}  {:<answer_width$}  {:>12}", "Profile", "Answer", "Time (s)");
+
+    for (profile_result, text) in results.iter().zip(texts.iter()) {
+        let time = match &profile_result.result {
+            Ok(result) => result.timings.total(),
+            Err(SolverError::Timeout(timeout)) => *timeout,
+            Err(_) => Duration::ZERO
+        };
+
+        println!("{:<profile_width$}  {:<answer_width$}  {:>12.6}", profile_result.profile, text, time.as_secs_f64());
+    }
+
+    for profile_result in results.iter() {
+        if let Err(error) = &profile_result.result {
+            if !matches!(error, SolverError::Timeout(_)) {
+                println!("Failed profile {}: {}", profile_result.profile, error);
+            }
+        }
+    }
+}
+
+fn table_answer(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("({} lines)", answer.to_string().lines().count())
+    }
+    else {
+        answer.to_string()
+    }
+}
+
+pub fn print_verification(summary: &RunSummary, book: &AnswerBook) -> bool {
+    let rows = table_rows(summary);
+    let answer_width = rows.iter().map(|x| x.text.len()).chain([6]).max().unwrap();
+    let mut all_passed = true;
+
+    println!("{:>3}  {:>4}  {:<answer_width$}  Status", "Day", "Part", "Answer");
+
+    for row in rows.iter() {
+        let verdict = match row.outcome {
+            Ok(answer) => book.check(row.day, row.part, answer),
+            Err(error) => Verdict::Fail(failure_reason(error))
+        };
+
+        if let Verdict::Fail(_) = verdict {
+            all_passed = false;
+        }
+
+        println!("{:>3}  {:>4}  {:<answer_width$}  {}", row.day, row.part, row.text, verdict);
+    }
+
+    print_skipped(summary);
+
+    all_passed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{solve_with_timeout, CANCEL_GRACE};
+    use crate::{cancel, params::Params, parse::ParseError, solver::{Answer, Solution, Solver, SolverError}};
+
+    struct Behaviour;
+
+    impl Solution for Behaviour {
+        const DAY: u32 = 99;
+
+        type Input = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &Self::Input, _params: &Params) -> Answer {
+            match input.as_str() {
+                "finish" => 42.into(),
+                "panic" => panic!("solver gave up"),
+                "stall" => {
+                    thread::sleep(CANCEL_GRACE * 2);
+                    0.into()
+                }
+                _ => {
+                    while !cancel::is_cancelled() {
+                        thread::sleep(Duration::from_millis(1));
+                    }
+
+                    0.into()
+                }
+            }
+        }
+
+        fn part2(input: &Self::Input, params: &Params) -> Answer {
+            Behaviour::part1(input, params)
+        }
+    }
+
+    fn solve(input: &str, timeout: Duration) -> Result<Answer, SolverError> {
+        solve_with_timeout(Solver::of::<Behaviour>(), input.to_string(), 1, Params::default(), timeout).map(|x| x.answer)
+    }
+
+    #[test]
+    fn timeout_returns_finished_answer() {
+        assert_eq!(solve("finish", Duration::from_secs(5)).unwrap(), 42.into());
+    }
+
+    #[test]
+    fn timeout_cancels_looping_solver() {
+        let timeout = Duration::from_millis(50);
+
+        assert!(matches!(solve("loop", timeout), Err(SolverError::Timeout(x)) if x == timeout));
+    }
+
+    #[test]
+    fn timeout_reports_panics_immediately() {
+        match solve("panic", Duration::from_secs(60)) {
+            Err(SolverError::Panicked(message)) => assert_eq!(message, "solver gave up"),
+            result => panic!("unexpected result {:?}", result.map(|x| x.to_string()))
+        }
+    }
+
+    #[test]
+    fn timeout_abandons_solver_ignoring_cancellation() {
+        assert!(matches!(solve("stall", Duration::from_millis(10)), Err(SolverError::Timeout(_))));
+        assert!(cancel::still_running() > 0);
+
+        thread::sleep(CANCEL_GRACE * 2);
+        assert_eq!(cancel::still_running(), 0);
+    }
+}
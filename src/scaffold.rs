@@ -0,0 +1,136 @@
+use std::{fmt::Display, fs, io, path::Path};
+
+pub const REGISTRY_PATH: &str = "src/days.rs";
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    InvalidDay(u32),
+    Exists(String),
+    Registry(String),
+    Io(String, io::Error)
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::InvalidDay(day) => write!(f, "Invalid day {}, expected 1 to 25", day),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists, refusing to overwrite it", path),
+            ScaffoldError::Registry(message) => write!(f, "Could not register the day in {}: {}", REGISTRY_PATH, message),
+            ScaffoldError::Io(path, error) => write!(f, "Could not write {}: {}", path, error)
+        }
+    }
+}
+
+pub fn module_path(day: u32) -> String {
+    format!("src/days/day{}.rs", day)
+}
+
+pub fn template(day: u32) -> String {
+    format!(
+"use crate::params::Params;
+use crate::parse::ParseError;
+use crate::solver::{{Answer, Solution, Solver}};
+
+pub struct Day{day};
+
+pub const SOLVER: Solver = Solver::of::<Day{day}>();
+
+impl Solution for Day{day} {{
+    const DAY: u32 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(|x| x.to_string()).collect())
+    }}
+
+    fn part1(_input: &Self::Input, _params: &Params) -> Answer {{
+        0.into()
+    }}
+
+    fn part2(_input: &Self::Input, _params: &Params) -> Answer {{
+        0.into()
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    examples! {{
+        super::Day{day};
+    }}
+}}
+")
+}
+
+pub fn register(registry: &str, day: u32) -> Result<String, ScaffoldError> {
+    let start = registry.find("days!(").ok_or_else(|| ScaffoldError::Registry("days! list not found".to_string()))? + "days!(".len();
+    let end = registry[start..].find(");").ok_or_else(|| ScaffoldError::Registry("days! list is not terminated".to_string()))? + start;
+    let name = format!("day{}", day);
+
+    let mut days: Vec<u32> = vec![];
+
+    for entry in registry[start..end].split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+        let number = entry.strip_prefix("day").and_then(|x| x.parse().ok()).ok_or_else(|| ScaffoldError::Registry(format!("unexpected entry {}", entry)))?;
+        days.push(number);
+    }
+
+    if days.contains(&day) {
+        return Err(ScaffoldError::Registry(format!("{} is already registered", name)));
+    }
+
+    days.push(day);
+    days.sort();
+
+    let list: String = days.iter().map(|x| format!("    day{},\n", x)).collect();
+    Ok(format!("{}\n{}{}", &registry[..start], list, &registry[end..]))
+}
+
+pub fn create(day: u32) -> Result<Vec<String>, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDay(day));
+    }
+
+    let module = module_path(day);
+    let input = crate::runner::default_input_path(day);
+
+    if Path::new(&module).exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    let registry = fs::read_to_string(REGISTRY_PATH).map_err(|x| ScaffoldError::Io(REGISTRY_PATH.to_string(), x))?;
+    let registry = register(&registry, day)?;
+    let mut created = vec![];
+
+    fs::write(&module, template(day)).map_err(|x| ScaffoldError::Io(module.clone(), x))?;
+    created.push(module);
+
+    if !Path::new(&input).exists() {
+        if let Some(parent) = Path::new(&input).parent() {
+            fs::create_dir_all(parent).map_err(|x| ScaffoldError::Io(input.clone(), x))?;
+        }
+
+        fs::write(&input, "").map_err(|x| ScaffoldError::Io(input.clone(), x))?;
+        created.push(input);
+    }
+
+    fs::write(REGISTRY_PATH, registry).map_err(|x| ScaffoldError::Io(REGISTRY_PATH.to_string(), x))?;
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::register;
+
+    #[test]
+    fn register_keeps_days_in_order() {
+        let registry = "days!(\n    day1,\n    day3,\n);\n";
+
+        assert_eq!(register(registry, 2).unwrap(), "days!(\n    day1,\n    day2,\n    day3,\n);\n");
+    }
+
+    #[test]
+    fn register_rejects_existing_day() {
+        assert!(register("days!(\n    day1,\n);\n", 1).is_err());
+    }
+}
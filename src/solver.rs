@@ -0,0 +1,224 @@
+use std::{fmt::Display, time::{Duration, Instant}};
+
+use crate::{days::SOLVERS, params::{Parameter, Params}, parse::ParseError, random::Random};
+
+pub trait Solution {
+    const DAY: u32;
+    const PARAMETERS: &'static [Parameter] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Params) -> Answer;
+    fn part2(input: &Self::Input, params: &Params) -> Answer;
+
+    fn generate(_random: &mut Random, _size: usize, _params: &Params) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u32,
+    pub parameters: &'static [Parameter],
+    solve: fn(&str, u32, &Params) -> Result<Solved, ParseError>,
+    generate: fn(&mut Random, usize, &Params) -> Option<String>
+}
+
+pub struct Solved {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Lines(Vec<String>)
+}
+
+#[derive(Debug)]
+pub enum SolverError {
+    UnknownDay(u32),
+    UnknownPart(u32),
+    UnknownParameter(String),
+    InvalidParameter(String, String),
+    Timeout(Duration),
+    Panicked(String),
+    Io(String, std::io::Error),
+    Parse(ParseError)
+}
+
+impl Display for SolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverError::UnknownDay(day) => write!(f, "No solver is registered for day {} (available days: {})", day, available_days()),
+            SolverError::UnknownPart(part) => write!(f, "Unknown part {}, expected 1 or 2", part),
+            SolverError::UnknownParameter(name) => write!(f, "Unknown parameter {}", name),
+            SolverError::InvalidParameter(name, value) => write!(f, "Invalid value {:?} for parameter {}", value, name),
+            SolverError::Timeout(timeout) => write!(f, "TIMEOUT after {:.3}s", timeout.as_secs_f64()),
+            SolverError::Panicked(message) => write!(f, "Solver panicked: {}", message),
+            SolverError::Io(path, error) => write!(f, "Could not read {}: {}", path, error),
+            SolverError::Parse(error) => write!(f, "Parse error at {}", error)
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n"))
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        (value as u64).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::Lines(value)
+    }
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Integer(_) => false,
+            Answer::Text(value) => value.contains('\n'),
+            Answer::Lines(lines) => lines.len() > 1
+        }
+    }
+}
+
+impl Solver {
+    pub const fn of<S: Solution>() -> Solver {
+        Solver {
+            day: S::DAY,
+            parameters: S::PARAMETERS,
+            solve: solve::<S>,
+            generate: S::generate
+        }
+    }
+
+    pub fn check_part(&self, part: u32) -> Result<(), SolverError> {
+        match part {
+            1 | 2 => Ok(()),
+            _ => Err(SolverError::UnknownPart(part))
+        }
+    }
+
+    pub fn params(&self, overrides: &[String]) -> Result<Params, SolverError> {
+        Params::resolve(self.parameters, overrides)
+    }
+
+    pub fn generate(&self, random: &mut Random, size: usize, params: &Params) -> Option<String> {
+        (self.generate)(random, size, params)
+    }
+
+    pub fn solve(&self, input: &str, part: u32, params: &Params) -> Result<Solved, SolverError> {
+        self.check_part(part)?;
+        (self.solve)(input, part, params).map_err(SolverError::Parse)
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: u32, params: &Params) -> Result<Solved, ParseError> {
+    let now = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = now.elapsed();
+
+    let now = Instant::now();
+    let answer = if part == 1 { S::part1(&parsed, params) } else { S::part2(&parsed, params) };
+    let solve_time = now.elapsed();
+
+    Ok(Solved {
+        answer,
+        parse_time,
+        solve_time
+    })
+}
+
+#[cfg(test)]
+pub fn check_example<S: Solution>(input: &str, part: u32, params: &[&str], expected: impl Into<Answer>) {
+    let overrides: Vec<String> = params.iter().map(|x| x.to_string()).collect();
+    let params = Params::resolve(S::PARAMETERS, &overrides).unwrap_or_else(|x| panic!("{}", x));
+    let solved = Solver::of::<S>().solve(&crate::input::normalize(input), part, &params).unwrap_or_else(|x| panic!("{}", x));
+
+    assert_eq!(solved.answer, expected.into(), "Day {} Part {}", S::DAY, part);
+}
+
+#[cfg(test)]
+pub fn check_generated<S: Solution>() {
+    let params = Params::defaults(S::PARAMETERS);
+
+    for seed in 0..20 {
+        let input = S::generate(&mut Random::new(seed), 50, &params).expect("no input generator");
+
+        if let Err(error) = S::parse(&crate::input::normalize(&input)) {
+            panic!("Day {} seed {}: {}", S::DAY, seed, error);
+        }
+    }
+}
+
+#[cfg(test)]
+pub fn check_generated_part<S: Solution>(part: u32, size: usize) {
+    let params = Params::defaults(S::PARAMETERS);
+
+    for seed in 0..20 {
+        let input = S::generate(&mut Random::new(seed), size, &params).expect("no input generator");
+        let params = params.clone();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            let _ = sender.send(Solver::of::<S>().solve(&crate::input::normalize(&input), part, &params));
+        });
+
+        match receiver.recv_timeout(Duration::from_secs(10)) {
+            Ok(Ok(_)) => {}
+            Ok(Err(error)) => panic!("Day {} seed {}: {}", S::DAY, seed, error),
+            Err(_) => panic!("Day {} seed {}: part {} did not finish", S::DAY, seed, part)
+        }
+    }
+}
+
+pub fn all() -> impl Iterator<Item = &'static Solver> {
+    SOLVERS.iter()
+}
+
+pub fn find(day: u32) -> Result<&'static Solver, SolverError> {
+    all().find(|x| x.day == day).ok_or(SolverError::UnknownDay(day))
+}
+
+pub fn available_days() -> String {
+    all().map(|x| x.day.to_string()).collect::<Vec<String>>().join(", ")
+}
@@ -0,0 +1,24 @@
+use std::{fs, path::Path, thread, time::{Duration, SystemTime}};
+
+pub const CLEAR_SCREEN: &str = "\x1B[2J\x1B[H";
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|x| x.modified()).ok()
+}
+
+pub fn watch(path: &Path, interval: Duration, mut action: impl FnMut()) -> ! {
+    let mut last_modified = modified(path);
+    action();
+
+    loop {
+        thread::sleep(interval);
+
+        let current_modified = modified(path);
+
+        if current_modified != last_modified {
+            last_modified = current_modified;
+            action();
+        }
+    }
+}